@@ -0,0 +1,147 @@
+//! Classification of recursive functions into primitive recursive functions and functions which use bounded minimization.
+
+use crate::{RecurFunction, RecurFunctionType};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Minimization function which can make recursive function undefined.
+pub struct PartialMinimization {
+    /// identifier of definition which contains minimization function.
+    pub identifier: String,
+    /// minimization function in source syntax.
+    pub function: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Class of recursive function.
+pub enum RecurFunctionClass {
+    /// function doesn't use minimization, so it is primitive recursive and defined on all arguments.
+    PrimitiveRecursive,
+    /// function uses bounded minimization, contains minimization functions which can make it undefined.
+    BoundedMinimization(Vec<PartialMinimization>),
+}
+
+impl RecurFunctionClass {
+    /// Returns true if function is primitive recursive.
+    pub fn is_primitive_recursive(&self) -> bool {
+        *self == RecurFunctionClass::PrimitiveRecursive
+    }
+
+    /// Returns true if function is defined on all arguments.
+    pub fn is_total(&self) -> bool {
+        match self {
+            RecurFunctionClass::PrimitiveRecursive => true,
+            RecurFunctionClass::BoundedMinimization(partial_minimizations) => {
+                partial_minimizations.is_empty()
+            }
+        }
+    }
+}
+
+/// Classifies recursive function.
+///
+/// # Arguments
+///
+/// * `identifier` - identifier of function, uses for reporting minimization functions written in place.
+/// * `function` - function to classify.
+///
+/// # Returns
+///
+/// Class of recursive function.
+pub fn classify(identifier: &str, function: &RecurFunction) -> RecurFunctionClass {
+    let mut has_minimization = false;
+    let mut partial_minimizations: Vec<PartialMinimization> = Vec::new();
+    collect_minimizations(
+        identifier,
+        function,
+        &mut has_minimization,
+        &mut partial_minimizations,
+    );
+    if has_minimization {
+        RecurFunctionClass::BoundedMinimization(partial_minimizations)
+    } else {
+        RecurFunctionClass::PrimitiveRecursive
+    }
+}
+
+/// Classifies all parsed recursive functions.
+///
+/// # Arguments
+///
+/// * `identifier_functions` - parsed identifiers and their function.
+///
+/// # Returns
+///
+/// BTreeMap<String, RecurFunctionClass> where key is identifier and value is class of its recursive function.
+pub fn classify_functions(
+    identifier_functions: &HashMap<String, RecurFunction>,
+) -> BTreeMap<String, RecurFunctionClass> {
+    identifier_functions
+        .iter()
+        .map(|(identifier, function)| (identifier.clone(), classify(identifier, function)))
+        .collect()
+}
+
+/// Walks through function and collects minimization functions which can make it undefined.
+fn collect_minimizations(
+    identifier: &str,
+    function: &RecurFunction,
+    has_minimization: &mut bool,
+    partial_minimizations: &mut Vec<PartialMinimization>,
+) {
+    let identifier = function.identifier().unwrap_or(identifier);
+    match function.function_type() {
+        RecurFunctionType::Zero(_)
+        | RecurFunctionType::Constant(_, _)
+        | RecurFunctionType::Successor
+        | RecurFunctionType::Projection(_, _) => {}
+        RecurFunctionType::Composition(base_function, functions) => {
+            collect_minimizations(
+                identifier,
+                base_function,
+                has_minimization,
+                partial_minimizations,
+            );
+            for function in functions {
+                collect_minimizations(
+                    identifier,
+                    function,
+                    has_minimization,
+                    partial_minimizations,
+                );
+            }
+        }
+        RecurFunctionType::Primitive(base_function, step_function) => {
+            collect_minimizations(
+                identifier,
+                base_function,
+                has_minimization,
+                partial_minimizations,
+            );
+            collect_minimizations(
+                identifier,
+                step_function,
+                has_minimization,
+                partial_minimizations,
+            );
+        }
+        RecurFunctionType::Minimization(base_function, _) => {
+            *has_minimization = true;
+            if !function.abstract_value().is_total() {
+                let partial_minimization = PartialMinimization {
+                    identifier: identifier.to_string(),
+                    function: function.function_type().to_string(),
+                };
+                if !partial_minimizations.contains(&partial_minimization) {
+                    partial_minimizations.push(partial_minimization);
+                }
+            }
+            collect_minimizations(
+                identifier,
+                base_function,
+                has_minimization,
+                partial_minimizations,
+            );
+        }
+    }
+}
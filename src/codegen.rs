@@ -0,0 +1,720 @@
+//! Generation of source code from recursive function definitions.
+//!
+//! Every definition becomes function of its arguments which signals if it is undefined or its result overflows.
+//! Primitive functions become loops, minimization functions become bounded searches
+//! and functions referred by identifier are called by their name instead of being inlined.
+//! Definitions are generated in order of their dependencies, identifiers which are reserved get '_' suffix.
+//! All languages share one traversal of definitions, which asks backend of language for its syntax.
+
+use crate::graph::dependency_graph;
+use crate::{RecurFunction, RecurFunctionType};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Words which can't be used as names of generated Rust functions.
+const RUST_RESERVED: [&str; 57] = [
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "async", "await", "dyn", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "typeof", "unsized", "virtual", "yield", "try", "gen", "Some", "None",
+    "Option", "u64", "main",
+];
+
+/// Words which can't be used as names of generated C functions.
+const C_RESERVED: [&str; 53] = [
+    "auto", "break", "case", "char", "const", "continue", "default", "do", "double", "else",
+    "enum", "extern", "float", "for", "goto", "if", "inline", "int", "long", "register",
+    "restrict", "return", "short", "signed", "sizeof", "static", "struct", "switch", "typedef",
+    "union", "unsigned", "void", "volatile", "while", "bool", "true", "false", "uint64_t", "main",
+    "abs", "div", "exit", "free", "malloc", "printf", "puts", "putchar", "calloc", "realloc",
+    "atoi", "atol", "rand", "system",
+];
+
+/// Words which can't be used as names of generated Python functions.
+const PYTHON_RESERVED: [&str; 38] = [
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield", "range", "print", "int",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Languages of generated source code.
+pub enum Language {
+    /// Rust module with `fn name(args...) -> Option<u64>` functions.
+    Rust,
+    /// C source with `bool name(args..., uint64_t *result_)` functions, which return false if result is undefined or overflows.
+    C,
+    /// Python module with `def name(args...)` functions over arbitrary-precision integers, which return None if result is undefined.
+    Python,
+    /// WebAssembly text module with exported `(func $name (param i64)... (result i64))` functions over unsigned numbers,
+    /// which trap if result is undefined or overflows.
+    Wat,
+}
+
+/// Generates source code with one function for every definition.
+///
+/// # Arguments
+///
+/// * `identifier_functions` - parsed identifiers and their function.
+/// * `language` - language of generated source code.
+///
+/// # Returns
+///
+/// Source code.
+pub fn generate(
+    identifier_functions: &HashMap<String, RecurFunction>,
+    language: Language,
+) -> String {
+    let backend: &dyn Backend = match language {
+        Language::Rust => &RustBackend,
+        Language::C => &CBackend,
+        Language::Python => &PythonBackend,
+        Language::Wat => &WatBackend,
+    };
+    let mut code = backend.header();
+    for identifier in definitions_order(identifier_functions) {
+        let mut generator = Generator::new(backend);
+        code.push('\n');
+        code.push_str(&generator.definition(&identifier, &identifier_functions[&identifier]));
+    }
+    code.push_str(&backend.footer());
+    code
+}
+
+/// Generates binary WebAssembly module with one exported function for every definition.
+///
+/// # Arguments
+///
+/// * `identifier_functions` - parsed identifiers and their function.
+///
+/// # Returns
+///
+/// Bytes of module which is assembled from generated WebAssembly text.
+pub fn generate_wasm(identifier_functions: &HashMap<String, RecurFunction>) -> Vec<u8> {
+    wat::parse_str(generate(identifier_functions, Language::Wat))
+        .expect("generated WebAssembly text is valid")
+}
+
+/// Orders identifiers so that every definition follows definitions it refers to, other identifiers are ordered by name.
+pub(crate) fn definitions_order(
+    identifier_functions: &HashMap<String, RecurFunction>,
+) -> Vec<String> {
+    let graph = dependency_graph(identifier_functions, None).unwrap_or_default();
+    let mut order = Vec::new();
+    let mut visited = BTreeSet::new();
+    for identifier in graph.keys() {
+        visit(identifier, &graph, &mut visited, &mut order);
+    }
+    order
+}
+
+/// Adds identifier to order after identifiers it depends on.
+fn visit(
+    identifier: &str,
+    graph: &BTreeMap<String, BTreeSet<String>>,
+    visited: &mut BTreeSet<String>,
+    order: &mut Vec<String>,
+) {
+    if !visited.insert(identifier.to_string()) {
+        return;
+    }
+    for dependency in graph.get(identifier).into_iter().flatten() {
+        visit(dependency, graph, visited, order);
+    }
+    order.push(identifier.to_string());
+}
+
+/// Syntax of language for statements of generated functions.
+/// Lines of statements are indented relatively to the current indentation of generator.
+trait Backend {
+    /// Words which get '_' suffix when they are used as names of functions.
+    fn reserved(&self) -> &[&str];
+
+    /// Header of generated source code.
+    fn header(&self) -> String;
+
+    /// Footer of generated source code.
+    fn footer(&self) -> String {
+        String::new()
+    }
+
+    /// Function with documentation, parameters, local variables, body and returned result.
+    fn function(
+        &self,
+        documentation: &str,
+        name: &str,
+        parameters: &[String],
+        locals: &[String],
+        body: &str,
+        result: &str,
+    ) -> String;
+
+    /// Literal of number.
+    fn literal(&self, number: u32) -> String;
+
+    /// Statements which assign result of function call to new local variable or return when it is undefined.
+    fn call(&self, local: &str, name: &str, arguments: &[String]) -> Vec<String>;
+
+    /// Statements which assign successor of argument to new local variable or return when it overflows.
+    fn successor(&self, local: &str, argument: &str) -> Vec<String>;
+
+    /// Statement which assigns value to new mutable local variable.
+    fn variable(&self, local: &str, value: &str) -> String;
+
+    /// Statement which assigns value to existing local variable.
+    fn assign(&self, local: &str, value: &str) -> String;
+
+    /// Statements which start loop with counter from 0 to count exclusive.
+    fn loop_start(&self, counter: &str, count: &str) -> Vec<String>;
+
+    /// Statements which end loop or search with given counter.
+    fn loop_end(&self, counter: &str) -> Vec<String>;
+
+    /// Statements which create local variable for found value and start search with tries from 0 to max inclusive.
+    fn search_start(&self, found: &str, tries: &str, max: u32) -> Vec<String>;
+
+    /// Statements which store tries and stop search if value is zero.
+    fn search_check(&self, value: &str, found: &str, tries: &str) -> Vec<String>;
+
+    /// Statements which assign found value to new local variable or return when nothing was found.
+    fn search_result(&self, local: &str, found: &str) -> Vec<String>;
+
+    /// Creates name of generated function from identifier.
+    fn name(&self, identifier: &str) -> String {
+        match self.reserved().contains(&identifier) {
+            true => format!("{identifier}_"),
+            false => identifier.to_string(),
+        }
+    }
+}
+
+/// Backend of Rust, where undefined results are None and propagated by '?'.
+struct RustBackend;
+
+impl Backend for RustBackend {
+    fn reserved(&self) -> &[&str] {
+        &RUST_RESERVED
+    }
+
+    fn header(&self) -> String {
+        "//! Functions generated by recur-func-parser from recursive function definitions.\n\
+         //! Every function returns None if it is undefined or its result overflows.\n\n\
+         #![allow(non_snake_case, unused_variables, unused_mut, unused_assignments, clippy::all)]\n"
+            .to_string()
+    }
+
+    fn function(
+        &self,
+        documentation: &str,
+        name: &str,
+        parameters: &[String],
+        _locals: &[String],
+        body: &str,
+        result: &str,
+    ) -> String {
+        let signature: Vec<String> = parameters
+            .iter()
+            .map(|parameter| format!("{parameter}: u64"))
+            .collect();
+        format!(
+            "/// {documentation}\npub fn {name}({}) -> Option<u64> {{\n{body}    Some({result})\n}}\n",
+            signature.join(", ")
+        )
+    }
+
+    fn literal(&self, number: u32) -> String {
+        format!("{number}u64")
+    }
+
+    fn call(&self, local: &str, name: &str, arguments: &[String]) -> Vec<String> {
+        vec![format!("let {local} = {name}({})?;", arguments.join(", "))]
+    }
+
+    fn successor(&self, local: &str, argument: &str) -> Vec<String> {
+        vec![format!("let {local} = {argument}.checked_add(1)?;")]
+    }
+
+    fn variable(&self, local: &str, value: &str) -> String {
+        format!("let mut {local} = {value};")
+    }
+
+    fn assign(&self, local: &str, value: &str) -> String {
+        format!("{local} = {value};")
+    }
+
+    fn loop_start(&self, counter: &str, count: &str) -> Vec<String> {
+        vec![format!("for {counter} in 0..{count} {{")]
+    }
+
+    fn loop_end(&self, _counter: &str) -> Vec<String> {
+        vec!["}".to_string()]
+    }
+
+    fn search_start(&self, found: &str, tries: &str, max: u32) -> Vec<String> {
+        vec![
+            format!("let mut {found} = None;"),
+            format!("for {tries} in 0..={max}u64 {{"),
+        ]
+    }
+
+    fn search_check(&self, value: &str, found: &str, tries: &str) -> Vec<String> {
+        vec![
+            format!("if {value} == 0 {{"),
+            format!("    {found} = Some({tries});"),
+            "    break;".to_string(),
+            "}".to_string(),
+        ]
+    }
+
+    fn search_result(&self, local: &str, found: &str) -> Vec<String> {
+        vec![format!("let {local} = {found}?;")]
+    }
+}
+
+/// Backend of C, where functions write result through pointer and return false if it is undefined or overflows.
+struct CBackend;
+
+impl Backend for CBackend {
+    fn reserved(&self) -> &[&str] {
+        &C_RESERVED
+    }
+
+    fn header(&self) -> String {
+        "/* Functions generated by recur-func-parser from recursive function definitions.\n\
+         \x20* Every function returns false if it is undefined or its result overflows,\n\
+         \x20* otherwise it stores result and returns true. */\n\n\
+         #include <stdbool.h>\n\
+         #include <stdint.h>\n"
+            .to_string()
+    }
+
+    fn function(
+        &self,
+        documentation: &str,
+        name: &str,
+        parameters: &[String],
+        _locals: &[String],
+        body: &str,
+        result: &str,
+    ) -> String {
+        let mut signature: Vec<String> = parameters
+            .iter()
+            .map(|parameter| format!("uint64_t {parameter}"))
+            .collect();
+        signature.push("uint64_t *result_".to_string());
+        format!(
+            "/* {documentation} */\nbool {name}({}) {{\n{body}    *result_ = {result};\n    return true;\n}}\n",
+            signature.join(", ")
+        )
+    }
+
+    fn literal(&self, number: u32) -> String {
+        format!("UINT64_C({number})")
+    }
+
+    fn call(&self, local: &str, name: &str, arguments: &[String]) -> Vec<String> {
+        let mut arguments = arguments.to_vec();
+        arguments.push(format!("&{local}"));
+        vec![
+            format!("uint64_t {local};"),
+            format!("if (!{name}({})) return false;", arguments.join(", ")),
+        ]
+    }
+
+    fn successor(&self, local: &str, argument: &str) -> Vec<String> {
+        vec![
+            format!("if ({argument} == UINT64_MAX) return false;"),
+            format!("uint64_t {local} = {argument} + 1;"),
+        ]
+    }
+
+    fn variable(&self, local: &str, value: &str) -> String {
+        format!("uint64_t {local} = {value};")
+    }
+
+    fn assign(&self, local: &str, value: &str) -> String {
+        format!("{local} = {value};")
+    }
+
+    fn loop_start(&self, counter: &str, count: &str) -> Vec<String> {
+        vec![format!(
+            "for (uint64_t {counter} = 0; {counter} < {count}; {counter}++) {{"
+        )]
+    }
+
+    fn loop_end(&self, _counter: &str) -> Vec<String> {
+        vec!["}".to_string()]
+    }
+
+    /// Found value is stored increased by one, so zero means that nothing was found.
+    fn search_start(&self, found: &str, tries: &str, max: u32) -> Vec<String> {
+        vec![
+            format!("uint64_t {found} = 0;"),
+            format!("for (uint64_t {tries} = 0; {tries} <= UINT64_C({max}); {tries}++) {{"),
+        ]
+    }
+
+    fn search_check(&self, value: &str, found: &str, tries: &str) -> Vec<String> {
+        vec![
+            format!("if ({value} == 0) {{"),
+            format!("    {found} = {tries} + 1;"),
+            "    break;".to_string(),
+            "}".to_string(),
+        ]
+    }
+
+    fn search_result(&self, local: &str, found: &str) -> Vec<String> {
+        vec![
+            format!("if ({found} == 0) return false;"),
+            format!("uint64_t {local} = {found} - 1;"),
+        ]
+    }
+}
+
+/// Backend of Python, where integers don't overflow and undefined results are None.
+struct PythonBackend;
+
+impl Backend for PythonBackend {
+    fn reserved(&self) -> &[&str] {
+        &PYTHON_RESERVED
+    }
+
+    fn header(&self) -> String {
+        "\"\"\"Functions generated by recur-func-parser from recursive function definitions.\n\n\
+         Every function returns None if it is undefined.\n\
+         \"\"\"\n"
+            .to_string()
+    }
+
+    fn function(
+        &self,
+        documentation: &str,
+        name: &str,
+        parameters: &[String],
+        _locals: &[String],
+        body: &str,
+        result: &str,
+    ) -> String {
+        format!(
+            "\ndef {name}({}):\n    \"\"\"{documentation}\"\"\"\n{body}    return {result}\n",
+            parameters.join(", ")
+        )
+    }
+
+    fn literal(&self, number: u32) -> String {
+        number.to_string()
+    }
+
+    fn call(&self, local: &str, name: &str, arguments: &[String]) -> Vec<String> {
+        vec![
+            format!("{local} = {name}({})", arguments.join(", ")),
+            format!("if {local} is None:"),
+            "    return None".to_string(),
+        ]
+    }
+
+    fn successor(&self, local: &str, argument: &str) -> Vec<String> {
+        vec![format!("{local} = {argument} + 1")]
+    }
+
+    fn variable(&self, local: &str, value: &str) -> String {
+        format!("{local} = {value}")
+    }
+
+    fn assign(&self, local: &str, value: &str) -> String {
+        format!("{local} = {value}")
+    }
+
+    fn loop_start(&self, counter: &str, count: &str) -> Vec<String> {
+        vec![format!("for {counter} in range({count}):")]
+    }
+
+    fn loop_end(&self, _counter: &str) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn search_start(&self, found: &str, tries: &str, max: u32) -> Vec<String> {
+        vec![
+            format!("{found} = None"),
+            format!("for {tries} in range({}):", u64::from(max) + 1),
+        ]
+    }
+
+    fn search_check(&self, value: &str, found: &str, tries: &str) -> Vec<String> {
+        vec![
+            format!("if {value} == 0:"),
+            format!("    {found} = {tries}"),
+            "    break".to_string(),
+        ]
+    }
+
+    fn search_result(&self, local: &str, found: &str) -> Vec<String> {
+        vec![
+            format!("if {found} is None:"),
+            "    return None".to_string(),
+            format!("{local} = {found}"),
+        ]
+    }
+}
+
+/// Backend of WebAssembly text, where undefined results trap and loops are blocks with counter which exit by branch.
+struct WatBackend;
+
+impl WatBackend {
+    /// Operand with value of local variable, parameter or literal.
+    fn operand(value: &str) -> String {
+        match value.starts_with('(') {
+            true => value.to_string(),
+            false => format!("(local.get ${value})"),
+        }
+    }
+}
+
+impl Backend for WatBackend {
+    fn reserved(&self) -> &[&str] {
+        &[]
+    }
+
+    fn header(&self) -> String {
+        ";; Module generated by recur-func-parser from recursive function definitions.\n\
+         ;; Every function takes and returns unsigned numbers, it traps if it is undefined or its result overflows.\n\n\
+         (module\n"
+            .to_string()
+    }
+
+    fn footer(&self) -> String {
+        ")\n".to_string()
+    }
+
+    fn function(
+        &self,
+        documentation: &str,
+        name: &str,
+        parameters: &[String],
+        locals: &[String],
+        body: &str,
+        result: &str,
+    ) -> String {
+        let mut signature = format!("(func ${name} (export \"{name}\")");
+        for parameter in parameters {
+            signature.push_str(&format!(" (param ${parameter} i64)"));
+        }
+        signature.push_str(" (result i64)");
+        let mut lines = vec![format!(";; {documentation}"), signature];
+        if !locals.is_empty() {
+            let declarations: Vec<String> = locals
+                .iter()
+                .map(|local| format!("(local ${local} i64)"))
+                .collect();
+            lines.push(format!("    {}", declarations.join(" ")));
+        }
+        lines.extend(body.lines().map(str::to_string));
+        lines.push(format!("    {})", Self::operand(result)));
+        lines.iter().map(|line| format!("    {line}\n")).collect()
+    }
+
+    fn literal(&self, number: u32) -> String {
+        format!("(i64.const {number})")
+    }
+
+    fn call(&self, local: &str, name: &str, arguments: &[String]) -> Vec<String> {
+        let operands: Vec<String> = arguments
+            .iter()
+            .map(|argument| format!(" {}", Self::operand(argument)))
+            .collect();
+        vec![format!(
+            "(local.set ${local} (call ${name}{}))",
+            operands.concat()
+        )]
+    }
+
+    fn successor(&self, local: &str, argument: &str) -> Vec<String> {
+        let argument = Self::operand(argument);
+        vec![
+            format!("(if (i64.eq {argument} (i64.const -1)) (then (unreachable)))"),
+            format!("(local.set ${local} (i64.add {argument} (i64.const 1)))"),
+        ]
+    }
+
+    fn variable(&self, local: &str, value: &str) -> String {
+        format!("(local.set ${local} {})", Self::operand(value))
+    }
+
+    fn assign(&self, local: &str, value: &str) -> String {
+        format!("(local.set ${local} {})", Self::operand(value))
+    }
+
+    fn loop_start(&self, counter: &str, count: &str) -> Vec<String> {
+        vec![
+            format!("(local.set ${counter} (i64.const 0))"),
+            "(block (loop".to_string(),
+            format!(
+                "    (br_if 1 (i64.ge_u (local.get ${counter}) {}))",
+                Self::operand(count)
+            ),
+        ]
+    }
+
+    fn loop_end(&self, counter: &str) -> Vec<String> {
+        vec![
+            format!("    (local.set ${counter} (i64.add (local.get ${counter}) (i64.const 1)))"),
+            "    (br 0)))".to_string(),
+        ]
+    }
+
+    /// Found value is stored increased by one, so zero means that nothing was found.
+    fn search_start(&self, found: &str, tries: &str, max: u32) -> Vec<String> {
+        vec![
+            format!("(local.set ${found} (i64.const 0))"),
+            format!("(local.set ${tries} (i64.const 0))"),
+            "(block (loop".to_string(),
+            format!("    (br_if 1 (i64.gt_u (local.get ${tries}) (i64.const {max})))"),
+        ]
+    }
+
+    fn search_check(&self, value: &str, found: &str, tries: &str) -> Vec<String> {
+        vec![
+            format!("(if (i64.eqz {}) (then", Self::operand(value)),
+            format!("    (local.set ${found} (i64.add (local.get ${tries}) (i64.const 1)))"),
+            "    (br 2)))".to_string(),
+        ]
+    }
+
+    fn search_result(&self, local: &str, found: &str) -> Vec<String> {
+        vec![
+            format!("(if (i64.eqz (local.get ${found})) (then (unreachable)))"),
+            format!("(local.set ${local} (i64.sub (local.get ${found}) (i64.const 1)))"),
+        ]
+    }
+}
+
+/// Generator of function which binds every intermediate result to local variable.
+struct Generator<'a> {
+    /// syntax of generated language.
+    backend: &'a dyn Backend,
+    /// generated lines of function body.
+    lines: Vec<String>,
+    /// indentation of next line.
+    indent: usize,
+    /// count of created local variables.
+    locals: usize,
+}
+
+impl<'a> Generator<'a> {
+    /// Creates generator of function in language of backend.
+    fn new(backend: &'a dyn Backend) -> Self {
+        Generator {
+            backend,
+            lines: Vec::new(),
+            indent: 0,
+            locals: 0,
+        }
+    }
+
+    /// Generates function for definition.
+    fn definition(&mut self, identifier: &str, function: &RecurFunction) -> String {
+        let parameters: Vec<String> = (1..=function.arguments_count())
+            .map(|number| format!("x_{number}"))
+            .collect();
+        self.indent = 1;
+        let result = self.expression(function, &parameters);
+        let locals: Vec<String> = (1..=self.locals)
+            .map(|number| format!("v_{number}"))
+            .collect();
+        self.backend.function(
+            &format!("{identifier} = {function};"),
+            &self.backend.name(identifier),
+            &parameters,
+            &locals,
+            &self.lines.concat(),
+            &result,
+        )
+    }
+
+    /// Adds line with current indentation.
+    fn line(&mut self, line: String) {
+        self.lines
+            .push(format!("{}{line}\n", "    ".repeat(self.indent)));
+    }
+
+    /// Adds lines with current indentation.
+    fn lines(&mut self, lines: Vec<String>) {
+        for line in lines {
+            self.line(line);
+        }
+    }
+
+    /// Creates new local variable name.
+    fn local(&mut self) -> String {
+        self.locals += 1;
+        format!("v_{}", self.locals)
+    }
+
+    /// Generates statements which compute function and returns variable or literal with its result.
+    fn expression(&mut self, function: &RecurFunction, arguments: &[String]) -> String {
+        let arguments = match function.arguments_count() {
+            0 => &[],
+            _ => arguments,
+        };
+        if let Some(identifier) = function.identifier() {
+            let local = self.local();
+            let name = self.backend.name(identifier);
+            self.lines(self.backend.call(&local, &name, arguments));
+            return local;
+        }
+        match function.function_type() {
+            RecurFunctionType::Zero(_) => self.backend.literal(0),
+            RecurFunctionType::Constant(_, number) => self.backend.literal(*number),
+            RecurFunctionType::Successor => {
+                let local = self.local();
+                self.lines(self.backend.successor(&local, &arguments[0]));
+                local
+            }
+            RecurFunctionType::Projection(_, argument_number) => {
+                arguments[*argument_number as usize - 1].clone()
+            }
+            RecurFunctionType::Composition(base_function, functions) => {
+                let results: Vec<String> = functions
+                    .iter()
+                    .map(|function| self.expression(function, arguments))
+                    .collect();
+                self.expression(base_function, &results)
+            }
+            RecurFunctionType::Primitive(base_function, step_function) => {
+                let (counter_max, base_arguments) = arguments
+                    .split_last()
+                    .expect("primitive function has at least one argument");
+                let base = self.expression(base_function, base_arguments);
+                let value = self.local();
+                self.line(self.backend.variable(&value, &base));
+                let counter = self.local();
+                self.lines(self.backend.loop_start(&counter, counter_max));
+                self.indent += 1;
+                let mut step_arguments = base_arguments.to_vec();
+                step_arguments.push(counter.clone());
+                step_arguments.push(value.clone());
+                let step = self.expression(step_function, &step_arguments);
+                self.line(self.backend.assign(&value, &step));
+                self.indent -= 1;
+                self.lines(self.backend.loop_end(&counter));
+                value
+            }
+            RecurFunctionType::Minimization(base_function, max) => {
+                let found = self.local();
+                let tries = self.local();
+                self.lines(self.backend.search_start(&found, &tries, *max));
+                self.indent += 1;
+                let mut base_arguments = arguments.to_vec();
+                base_arguments.push(tries.clone());
+                let base = self.expression(base_function, &base_arguments);
+                self.lines(self.backend.search_check(&base, &found, &tries));
+                self.indent -= 1;
+                self.lines(self.backend.loop_end(&tries));
+                let result = self.local();
+                self.lines(self.backend.search_result(&result, &found));
+                result
+            }
+        }
+    }
+}
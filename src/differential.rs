@@ -0,0 +1,197 @@
+//! Differential testing of recursive functions against reference implementations written in Rust.
+//!
+//! Reference implementation is registered for identifier of function, then function is executed
+//! on random arguments with limited number of steps and its results are compared with reference results.
+//! Found counterexamples are shrunk to minimal ones.
+//!
+//! ```
+//! use recur_func_parser::differential::{DifferentialOptions, DifferentialTester};
+//! use recur_func_parser::parse_recur_functions;
+//!
+//! let identifier_functions = parse_recur_functions(
+//!     "addition = [$p1.1, ($s:$p3.3)]; multiplication = [$z , (addition: $p3.3,$p3.1)];",
+//! )
+//! .unwrap();
+//! let mut tester = DifferentialTester::new();
+//! tester.register("multiplication", |arguments| {
+//!     arguments[0].checked_mul(arguments[1])
+//! });
+//! let mismatches = tester
+//!     .test(&identifier_functions, &DifferentialOptions::default())
+//!     .unwrap();
+//! assert!(mismatches.is_empty());
+//! ```
+
+use crate::equiv::{evaluate, shrink, Counterexample, Evaluation, DEFAULT_FUEL};
+use crate::{RecurFunction, RecurFunctionParseError};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Reference implementation of function, returns None if result is undefined.
+pub type ReferenceFunction = Box<dyn Fn(&[u32]) -> Option<u32>>;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Options of differential testing.
+pub struct DifferentialOptions {
+    /// number of random argument tuples for every function.
+    pub cases: u32,
+    /// max value of every random argument.
+    pub max_argument: u32,
+    /// max number of steps of every execution.
+    pub fuel: u64,
+    /// seed of random arguments generator.
+    pub seed: u64,
+}
+
+impl Default for DifferentialOptions {
+    fn default() -> Self {
+        DifferentialOptions {
+            cases: 100,
+            max_argument: 20,
+            fuel: DEFAULT_FUEL,
+            seed: 0x2545_f491_4f6c_dd1d,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Disagreement of function with its reference implementation.
+pub struct Mismatch {
+    /// identifier of function.
+    pub identifier: String,
+    /// minimal found arguments with result of reference implementation as first result and result of function as second.
+    pub counterexample: Counterexample,
+}
+
+impl fmt::Display for Mismatch {
+    /// Formats mismatch, for example: multiplication(1, 2): expected 2, got 3.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arguments: Vec<String> = self
+            .counterexample
+            .arguments
+            .iter()
+            .map(|argument| argument.to_string())
+            .collect();
+        write!(
+            f,
+            "{}({}): expected {}, got {}",
+            self.identifier,
+            arguments.join(", "),
+            self.counterexample.first,
+            self.counterexample.second
+        )
+    }
+}
+
+#[derive(Default)]
+/// Registry of reference implementations which are used for differential testing.
+pub struct DifferentialTester {
+    /// identifiers and their reference implementation.
+    references: BTreeMap<String, ReferenceFunction>,
+}
+
+impl DifferentialTester {
+    /// Creates tester without reference implementations.
+    pub fn new() -> DifferentialTester {
+        DifferentialTester::default()
+    }
+
+    /// Registers reference implementation of function, replaces previous implementation of the same identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - identifier of function.
+    /// * `reference` - reference implementation, gets arguments and returns None if result is undefined.
+    pub fn register(
+        &mut self,
+        identifier: &str,
+        reference: impl Fn(&[u32]) -> Option<u32> + 'static,
+    ) {
+        self.references
+            .insert(identifier.to_string(), Box::new(reference));
+    }
+
+    /// Compares every function which has reference implementation with it on random arguments.
+    /// Execution which runs out of steps is a mismatch if reference result is defined, otherwise it is skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier_functions` - parsed identifiers and their function.
+    /// * `options` - options of testing.
+    ///
+    /// # Returns
+    ///
+    /// Mismatches sorted by identifier, at most one for every function,
+    /// or RecurFunctionParseError wraped into Result if some registered identifier is undefined.
+    pub fn test(
+        &self,
+        identifier_functions: &HashMap<String, RecurFunction>,
+        options: &DifferentialOptions,
+    ) -> Result<Vec<Mismatch>, RecurFunctionParseError> {
+        let mut random = Random(options.seed.max(1));
+        let mut mismatches = Vec::new();
+        for (identifier, reference) in self.references.iter() {
+            let function = identifier_functions.get(identifier).ok_or(
+                RecurFunctionParseError::UndefinedIdentifier(identifier.clone()),
+            )?;
+            let disagree =
+                |arguments: &[u32]| compare(reference, function, arguments, options.fuel);
+            for case in 0..options.cases {
+                let arguments: Vec<u32> = (0..function.arguments_count())
+                    .map(|_| match case {
+                        0 => 0,
+                        _ => random.next_below(options.max_argument as u64 + 1) as u32,
+                    })
+                    .collect();
+                if let Some(counterexample) = disagree(&arguments) {
+                    mismatches.push(Mismatch {
+                        identifier: identifier.clone(),
+                        counterexample: shrink(counterexample, disagree),
+                    });
+                    break;
+                }
+            }
+        }
+        Ok(mismatches)
+    }
+}
+
+/// Compares function with its reference implementation on given arguments.
+///
+/// # Returns
+///
+/// Counterexample if results are different, None if they are the same
+/// or if execution ran out of steps and reference result is undefined.
+fn compare(
+    reference: &ReferenceFunction,
+    function: &RecurFunction,
+    arguments: &[u32],
+    fuel: u64,
+) -> Option<Counterexample> {
+    let expected = match reference(arguments) {
+        Some(number) => Evaluation::Defined(number),
+        None => Evaluation::Undefined,
+    };
+    let actual = evaluate(function, arguments, fuel);
+    if actual == expected || (!actual.is_conclusive() && expected == Evaluation::Undefined) {
+        return None;
+    }
+    Some(Counterexample {
+        arguments: arguments.to_vec(),
+        first: expected,
+        second: actual,
+    })
+}
+
+/// Xorshift generator of pseudorandom numbers, state must not be zero.
+struct Random(u64);
+
+impl Random {
+    /// Returns next pseudorandom number which is less than bound.
+    fn next_below(&mut self, bound: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % bound
+    }
+}
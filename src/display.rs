@@ -0,0 +1,65 @@
+//! Formatting of recursive functions in source syntax.
+//!
+//! Definition is formatted by its function type, inner functions which were referred by identifier
+//! are formatted as identifier, so definition `f = g;` is formatted as definition of `g`.
+
+use crate::{RecurFunction, RecurFunctionType};
+use std::fmt;
+
+impl fmt::Display for RecurFunction {
+    /// Formats definition of function in source syntax.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.function_type().fmt(f)
+    }
+}
+
+/// Inner function which is formatted as identifier if it was referred by identifier.
+struct Reference<'a>(&'a RecurFunction);
+
+impl fmt::Display for Reference<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.identifier() {
+            Some(identifier) => write!(f, "{identifier}"),
+            None => self.0.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for RecurFunctionType {
+    /// Formats function type in source syntax.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecurFunctionType::Zero(0) => write!(f, "$z"),
+            RecurFunctionType::Zero(arguments_count) => write!(f, "$z{arguments_count}"),
+            RecurFunctionType::Constant(0, number) => write!(f, "{number}"),
+            RecurFunctionType::Constant(arguments_count, number) => {
+                write!(f, "$c{arguments_count}.{number}")
+            }
+            RecurFunctionType::Successor => write!(f, "$s"),
+            RecurFunctionType::Projection(arguments_count, argument_number) => {
+                write!(f, "$p{arguments_count}.{argument_number}")
+            }
+            RecurFunctionType::Composition(base_function, functions) => {
+                write!(f, "({} :", Reference(base_function))?;
+                for (i, function) in functions.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, " {}", Reference(function))?;
+                }
+                write!(f, ")")
+            }
+            RecurFunctionType::Primitive(base_function, step_function) => {
+                write!(
+                    f,
+                    "[{}, {}]",
+                    Reference(base_function),
+                    Reference(step_function)
+                )
+            }
+            RecurFunctionType::Minimization(base_function, max) => {
+                write!(f, "{{{}, {max}}}", Reference(base_function))
+            }
+        }
+    }
+}
@@ -0,0 +1,468 @@
+//! Enumeration of all well-formed recursive functions with given arguments count and size.
+//!
+//! Size of function is count of nodes in its syntax tree: zero, constant, successor and projection functions
+//! and functions referred by identifier have size 1, other functions have size 1 plus sizes of their inner functions.
+//! Enumerated functions respect the same arguments count rules as parser.
+//! Functions are generated lazily, iterators keep only syntax trees on the path to the current function.
+
+use crate::{RecurFunction, RecurFunctionType};
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+use std::time::Instant;
+
+/// Default max tries of enumerated minimization functions.
+pub const DEFAULT_MINIMIZATION_MAX: u32 = 10;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Options of enumeration.
+pub struct EnumerationOptions {
+    /// max tries of minimization functions, None to exclude minimization functions.
+    pub minimization_max: Option<u32>,
+    /// max number of constant functions, None to exclude constant functions except zero.
+    pub max_constant: Option<u32>,
+    /// identifiers and their functions which are enumerated as functions of size 1 referred by identifier.
+    pub definitions: BTreeMap<String, RecurFunction>,
+}
+
+impl Default for EnumerationOptions {
+    fn default() -> Self {
+        EnumerationOptions {
+            minimization_max: Some(DEFAULT_MINIMIZATION_MAX),
+            max_constant: None,
+            definitions: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+/// Budget of enumeration, iterators stop generating functions when it runs out.
+pub struct EnumerationBudget {
+    /// time after which no more nodes are generated, None if time isn't limited.
+    pub deadline: Option<Instant>,
+    /// max number of generated nodes of syntax trees, None if count isn't limited.
+    pub max_nodes: Option<u64>,
+}
+
+/// Enumerator of well-formed functions which generates functions lazily one by one.
+pub struct TermEnumerator {
+    /// generator of syntax trees shared by iterators.
+    generator: Generator,
+}
+
+impl TermEnumerator {
+    /// Creates enumerator with given options.
+    pub fn new(options: EnumerationOptions) -> TermEnumerator {
+        TermEnumerator::with_budget(options, EnumerationBudget::default())
+    }
+
+    /// Creates enumerator with given options which stops when budget runs out.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - options of enumeration.
+    /// * `budget` - budget of enumeration shared by all iterators of enumerator.
+    ///
+    /// # Returns
+    ///
+    /// The enumerator.
+    pub fn with_budget(options: EnumerationOptions, budget: EnumerationBudget) -> TermEnumerator {
+        let definitions = options
+            .definitions
+            .iter()
+            .map(|(identifier, function)| {
+                let mut function = function.clone();
+                function.identifier = Some(identifier.clone());
+                function
+            })
+            .collect();
+        TermEnumerator {
+            generator: Generator {
+                options: Rc::new(options),
+                definitions: Rc::new(definitions),
+                existing: Rc::new(RefCell::new(HashMap::new())),
+                budget: Rc::new(Budget {
+                    deadline: budget.deadline,
+                    nodes_left: Cell::new(budget.max_nodes),
+                    exhausted: Cell::new(false),
+                }),
+            },
+        }
+    }
+
+    /// Returns true if budget ran out and iterators could stop before all functions were generated.
+    pub fn is_exhausted(&self) -> bool {
+        self.generator.budget.exhausted.get()
+    }
+
+    /// Yields every well-formed function with given arguments count and size.
+    ///
+    /// # Arguments
+    ///
+    /// * `arguments_count` - arguments count of functions.
+    /// * `size` - count of nodes in syntax tree of functions.
+    ///
+    /// # Returns
+    ///
+    /// Iterator over functions.
+    pub fn terms(&self, arguments_count: u32, size: u32) -> impl Iterator<Item = RecurFunction> {
+        let generator = self.generator.clone();
+        self.generator
+            .terms(arguments_count, size)
+            .map(move |term| generator.function(&term))
+    }
+}
+
+/// Syntax tree of enumerated function, subtrees are shared between trees and definitions are referred by index.
+enum Term {
+    /// zero, constant, successor or projection function.
+    Leaf(RecurFunctionType),
+    /// function of definition with this index.
+    Definition(usize),
+    /// composition function with base function and functions to use.
+    Composition(Rc<Term>, Vec<Rc<Term>>),
+    /// primitive function with base function and step function.
+    Primitive(Rc<Term>, Rc<Term>),
+    /// minimization function with base function and max tries.
+    Minimization(Rc<Term>, u32),
+}
+
+impl Term {
+    /// Checks if function without arguments has zero or constant function without arguments
+    /// among its functions to use, parser gives them arguments count from context.
+    fn has_inferred_arguments_count(&self) -> bool {
+        match self {
+            Term::Leaf(RecurFunctionType::Zero(_) | RecurFunctionType::Constant(_, _)) => true,
+            Term::Composition(_, functions) => functions
+                .iter()
+                .any(|function| function.has_inferred_arguments_count()),
+            _ => false,
+        }
+    }
+}
+
+/// Boxed iterator over syntax trees.
+type Terms = Box<dyn Iterator<Item = Rc<Term>>>;
+
+#[derive(Clone)]
+/// Generator of syntax trees which builds every tree when iterator reaches it.
+struct Generator {
+    /// options of enumeration.
+    options: Rc<EnumerationOptions>,
+    /// functions of definitions with their identifier.
+    definitions: Rc<Vec<RecurFunction>>,
+    /// arguments counts and sizes with answer whether some syntax tree has them.
+    existing: Rc<RefCell<HashMap<(u32, u32), bool>>>,
+    /// budget left for generation.
+    budget: Rc<Budget>,
+}
+
+/// Budget left for generation of syntax trees.
+struct Budget {
+    /// time after which no more nodes are generated.
+    deadline: Option<Instant>,
+    /// count of nodes which can be generated.
+    nodes_left: Cell<Option<u64>>,
+    /// budget ran out.
+    exhausted: Cell<bool>,
+}
+
+impl Budget {
+    /// Spends budget of one generated node, returns false if budget ran out.
+    fn spend(&self) -> bool {
+        if self.exhausted.get() {
+            return false;
+        }
+        let nodes_left = match self.nodes_left.get() {
+            Some(nodes_left) => nodes_left.checked_sub(1).map(Some),
+            None => Some(None),
+        };
+        match nodes_left {
+            Some(nodes_left)
+                if self
+                    .deadline
+                    .is_none_or(|deadline| Instant::now() < deadline) =>
+            {
+                self.nodes_left.set(nodes_left);
+                true
+            }
+            _ => {
+                self.exhausted.set(true);
+                false
+            }
+        }
+    }
+}
+
+impl Generator {
+    /// Generates syntax trees with exactly given arguments count and size.
+    fn terms(&self, arguments_count: u32, size: u32) -> Terms {
+        let mut terms: Terms = Box::new(std::iter::empty());
+        if size == 1 {
+            terms = Box::new(terms.chain(self.leaves(arguments_count)));
+        }
+        let generator = self.clone();
+        let compositions = (0..=size.saturating_sub(2))
+            .filter(move |functions_count| arguments_count == 0 || *functions_count > 0)
+            .flat_map(move |functions_count| {
+                let generator = generator.clone();
+                (1..size - functions_count).flat_map(move |base_size| {
+                    let rest_size = size - 1 - base_size;
+                    let generator = generator.clone();
+                    let base_functions: Terms = match generator.has_sequences(
+                        arguments_count,
+                        functions_count,
+                        rest_size,
+                    ) {
+                        true => generator.terms(functions_count, base_size),
+                        false => Box::new(std::iter::empty()),
+                    };
+                    base_functions.flat_map(move |base_function| {
+                        generator
+                            .sequences(arguments_count, functions_count, rest_size)
+                            .map(move |functions| {
+                                Rc::new(Term::Composition(base_function.clone(), functions))
+                            })
+                    })
+                })
+            });
+        terms = Box::new(terms.chain(compositions));
+        if arguments_count > 0 {
+            let generator = self.clone();
+            let primitives = (1..size.saturating_sub(1)).flat_map(move |base_size| {
+                let generator = generator.clone();
+                let step_functions: Terms = match generator.has_bases(arguments_count, base_size) {
+                    true => generator.terms(arguments_count + 1, size - 1 - base_size),
+                    false => Box::new(std::iter::empty()),
+                };
+                step_functions.flat_map(move |step_function| {
+                    generator
+                        .bases(arguments_count, base_size)
+                        .map(move |base_function| {
+                            Rc::new(Term::Primitive(base_function, step_function.clone()))
+                        })
+                })
+            });
+            terms = Box::new(terms.chain(primitives));
+        }
+        if let (Some(max), true) = (self.options.minimization_max, size > 1) {
+            let minimizations = self
+                .terms(arguments_count + 1, size - 1)
+                .map(move |base_function| Rc::new(Term::Minimization(base_function, max)));
+            terms = Box::new(terms.chain(minimizations));
+        }
+        let budget = self.budget.clone();
+        Box::new(terms.take_while(move |_| budget.spend()))
+    }
+
+    /// Generates base functions of primitive function with given arguments count and size,
+    /// functions without arguments follow functions with one argument less if arguments count is greater than 1.
+    /// Functions without arguments whose arguments count parser takes from primitive function are skipped.
+    fn bases(&self, arguments_count: u32, size: u32) -> Terms {
+        let bases = self.terms(arguments_count - 1, size);
+        if arguments_count < 2 {
+            return bases;
+        }
+        let nullary_bases = self
+            .terms(0, size)
+            .filter(|base_function| !base_function.has_inferred_arguments_count());
+        Box::new(bases.chain(nullary_bases))
+    }
+
+    /// Generates syntax trees of size 1 with given arguments count, definitions go first.
+    fn leaves(&self, arguments_count: u32) -> Terms {
+        let mut leaves: Vec<Rc<Term>> = (0..self.definitions.len())
+            .filter(|index| self.definitions[*index].arguments_count() == arguments_count)
+            .map(|index| Rc::new(Term::Definition(index)))
+            .collect();
+        leaves.push(Rc::new(Term::Leaf(RecurFunctionType::Zero(
+            arguments_count,
+        ))));
+        if let Some(max_constant) = self.options.max_constant {
+            for number in 0..=max_constant {
+                leaves.push(Rc::new(Term::Leaf(RecurFunctionType::Constant(
+                    arguments_count,
+                    number,
+                ))));
+            }
+        }
+        if arguments_count == 1 {
+            leaves.push(Rc::new(Term::Leaf(RecurFunctionType::Successor)));
+        }
+        for argument_number in 1..=arguments_count {
+            leaves.push(Rc::new(Term::Leaf(RecurFunctionType::Projection(
+                arguments_count,
+                argument_number,
+            ))));
+        }
+        Box::new(leaves.into_iter())
+    }
+
+    /// Generates sequences of functions of composition with given arguments count, count and total size.
+    fn sequences(
+        &self,
+        arguments_count: u32,
+        functions_count: u32,
+        total_size: u32,
+    ) -> Box<dyn Iterator<Item = Vec<Rc<Term>>>> {
+        if functions_count == 0 {
+            return match total_size {
+                0 => Box::new(std::iter::once(Vec::new())),
+                _ => Box::new(std::iter::empty()),
+            };
+        }
+        let generator = self.clone();
+        Box::new(
+            (1..=total_size.saturating_sub(functions_count - 1)).flat_map(move |first_size| {
+                let generator = generator.clone();
+                let first_functions: Terms = match generator.has_sequences(
+                    arguments_count,
+                    functions_count - 1,
+                    total_size - first_size,
+                ) {
+                    true => generator.terms(arguments_count, first_size),
+                    false => Box::new(std::iter::empty()),
+                };
+                first_functions.flat_map(move |first_function| {
+                    generator
+                        .sequences(
+                            arguments_count,
+                            functions_count - 1,
+                            total_size - first_size,
+                        )
+                        .map(move |tail| {
+                            let mut sequence = vec![first_function.clone()];
+                            sequence.extend(tail);
+                            sequence
+                        })
+                })
+            }),
+        )
+    }
+
+    /// Checks if some syntax tree has given arguments count and size, answers are stored in cache.
+    fn has_terms(&self, arguments_count: u32, size: u32) -> bool {
+        if size <= 1 {
+            return size == 1;
+        }
+        if let Some(exists) = self.existing.borrow().get(&(arguments_count, size)) {
+            return *exists;
+        }
+        let compositions = (0..=size - 2)
+            .filter(|functions_count| arguments_count == 0 || *functions_count > 0)
+            .any(|functions_count| {
+                (1..size - functions_count).any(|base_size| {
+                    self.has_terms(functions_count, base_size)
+                        && self.has_sequences(
+                            arguments_count,
+                            functions_count,
+                            size - 1 - base_size,
+                        )
+                })
+            });
+        let primitives = arguments_count > 0
+            && (1..size - 1).any(|base_size| {
+                self.has_bases(arguments_count, base_size)
+                    && self.has_terms(arguments_count + 1, size - 1 - base_size)
+            });
+        let minimizations = self.options.minimization_max.is_some()
+            && self.has_terms(arguments_count + 1, size - 1);
+        let exists = compositions || primitives || minimizations;
+        self.existing
+            .borrow_mut()
+            .insert((arguments_count, size), exists);
+        exists
+    }
+
+    /// Checks if some base function of primitive function has given arguments count and size.
+    fn has_bases(&self, arguments_count: u32, size: u32) -> bool {
+        self.has_terms(arguments_count - 1, size)
+            || (arguments_count > 1 && self.has_terms(0, size))
+    }
+
+    /// Checks if some sequence of functions of composition has given arguments count, count and total size.
+    fn has_sequences(&self, arguments_count: u32, functions_count: u32, total_size: u32) -> bool {
+        if functions_count == 0 {
+            return total_size == 0;
+        }
+        (1..=total_size.saturating_sub(functions_count - 1)).any(|first_size| {
+            self.has_terms(arguments_count, first_size)
+                && self.has_sequences(
+                    arguments_count,
+                    functions_count - 1,
+                    total_size - first_size,
+                )
+        })
+    }
+
+    /// Builds function of syntax tree, functions of definitions are copied into it.
+    fn function(&self, term: &Term) -> RecurFunction {
+        match term {
+            Term::Leaf(function_type) => {
+                let arguments_count = match function_type {
+                    RecurFunctionType::Successor => 1,
+                    RecurFunctionType::Zero(arguments_count)
+                    | RecurFunctionType::Constant(arguments_count, _)
+                    | RecurFunctionType::Projection(arguments_count, _) => *arguments_count,
+                    _ => 0,
+                };
+                RecurFunction::new(function_type.clone(), arguments_count)
+            }
+            Term::Definition(index) => self.definitions[*index].clone(),
+            Term::Composition(base_function, functions) => {
+                let functions: Vec<RecurFunction> = functions
+                    .iter()
+                    .map(|function| self.function(function))
+                    .collect();
+                let arguments_count = functions
+                    .first()
+                    .map_or(0, |function| function.arguments_count());
+                RecurFunction::new(
+                    RecurFunctionType::Composition(
+                        Box::new(self.function(base_function)),
+                        functions,
+                    ),
+                    arguments_count,
+                )
+            }
+            Term::Primitive(base_function, step_function) => {
+                let step_function = self.function(step_function);
+                let arguments_count = step_function.arguments_count() - 1;
+                RecurFunction::new(
+                    RecurFunctionType::Primitive(
+                        Box::new(self.function(base_function)),
+                        Box::new(step_function),
+                    ),
+                    arguments_count,
+                )
+            }
+            Term::Minimization(base_function, max) => {
+                let base_function = self.function(base_function);
+                let arguments_count = base_function.arguments_count() - 1;
+                RecurFunction::new(
+                    RecurFunctionType::Minimization(Box::new(base_function), *max),
+                    arguments_count,
+                )
+            }
+        }
+    }
+}
+
+/// Enumerates every well-formed function with given arguments count and size.
+///
+/// # Arguments
+///
+/// * `arguments_count` - arguments count of functions.
+/// * `size` - count of nodes in syntax tree of functions.
+/// * `options` - options of enumeration.
+///
+/// # Returns
+///
+/// Iterator over functions.
+pub fn enumerate_terms(
+    arguments_count: u32,
+    size: u32,
+    options: &EnumerationOptions,
+) -> impl Iterator<Item = RecurFunction> {
+    TermEnumerator::new(options.clone()).terms(arguments_count, size)
+}
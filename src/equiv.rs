@@ -0,0 +1,212 @@
+//! Bounded equivalence checking of two recursive functions with the same arguments count.
+//!
+//! Functions are executed on all argument tuples whose arguments don't exceed given bound,
+//! every execution uses limited number of steps. Found counterexample is shrunk to a minimal one.
+
+use crate::{execute_with_fuel, RecurFunction};
+use std::fmt;
+use thiserror::Error;
+
+/// Default number of steps which can be used by one execution.
+pub const DEFAULT_FUEL: u64 = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Result of execution with limited number of steps.
+pub enum Evaluation {
+    /// result is defined and equals this number.
+    Defined(u32),
+    /// result is undefined.
+    Undefined,
+    /// steps ran out or number overflowed before result was found.
+    Exhausted,
+}
+
+impl Evaluation {
+    /// Returns true if execution finished before steps ran out.
+    pub fn is_conclusive(&self) -> bool {
+        *self != Evaluation::Exhausted
+    }
+}
+
+impl fmt::Display for Evaluation {
+    /// Formats evaluation, for example: 5, undefined or out of fuel.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Evaluation::Defined(number) => write!(f, "{number}"),
+            Evaluation::Undefined => write!(f, "undefined"),
+            Evaluation::Exhausted => write!(f, "out of fuel"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Arguments on which two functions give different results.
+pub struct Counterexample {
+    /// arguments of functions.
+    pub arguments: Vec<u32>,
+    /// result of first function.
+    pub first: Evaluation,
+    /// result of second function.
+    pub second: Evaluation,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Result of bounded equivalence check.
+pub enum Equivalence {
+    /// functions agree on all checked arguments, contains count of checked argument tuples
+    /// and count of tuples which were skipped because some execution ran out of steps.
+    Equivalent { checked: u64, exhausted: u64 },
+    /// functions disagree on counterexample.
+    Counterexample(Counterexample),
+}
+
+#[derive(Debug, Error, PartialEq)]
+/// Errors which can apear when checking equivalence.
+pub enum EquivalenceError {
+    #[error("Functions have different arguments count: {0} and {1}")]
+    /// Error which signals that compared functions have different arguments count.
+    ArgumentsCountMismatch(u32, u32),
+}
+
+/// Executes recursive function on given arguments using limited number of steps.
+///
+/// # Arguments
+///
+/// * `function` - function to execute.
+/// * `arguments` - arguments to use for calculations.
+/// * `fuel` - max number of steps, every executed function uses one step.
+///
+/// # Returns
+///
+/// Result of execution.
+pub fn evaluate(function: &RecurFunction, arguments: &[u32], fuel: u64) -> Evaluation {
+    let mut fuel = fuel;
+    match execute_with_fuel(function, arguments, &mut fuel) {
+        Some(Some(number)) => Evaluation::Defined(number),
+        Some(None) => Evaluation::Undefined,
+        None => Evaluation::Exhausted,
+    }
+}
+
+/// Checks that two functions agree on all argument tuples whose arguments are not greater than bound.
+/// Definedness differences are mismatches, tuples on which some execution ran out of steps are skipped.
+///
+/// # Arguments
+///
+/// * `first` - first function.
+/// * `second` - second function.
+/// * `up_to` - max value of every argument.
+/// * `fuel` - max number of steps of every execution.
+///
+/// # Returns
+///
+/// Result of check with minimal counterexample if functions disagree
+/// or EquivalenceError wraped into Result if functions have different arguments count.
+pub fn check_equivalence(
+    first: &RecurFunction,
+    second: &RecurFunction,
+    up_to: u32,
+    fuel: u64,
+) -> Result<Equivalence, EquivalenceError> {
+    if first.arguments_count() != second.arguments_count() {
+        return Err(EquivalenceError::ArgumentsCountMismatch(
+            first.arguments_count(),
+            second.arguments_count(),
+        ));
+    }
+    let mut arguments = vec![0; first.arguments_count() as usize];
+    let mut checked: u64 = 0;
+    let mut exhausted: u64 = 0;
+    loop {
+        checked += 1;
+        match compare(first, second, &arguments, fuel) {
+            Comparison::Agree => {}
+            Comparison::Inconclusive => exhausted += 1,
+            Comparison::Disagree(counterexample) => {
+                return Ok(Equivalence::Counterexample(shrink(
+                    counterexample,
+                    |arguments| match compare(first, second, arguments, fuel) {
+                        Comparison::Disagree(counterexample) => Some(counterexample),
+                        _ => None,
+                    },
+                )))
+            }
+        }
+        let Some(i) = arguments.iter().rposition(|argument| *argument < up_to) else {
+            break;
+        };
+        arguments[i] += 1;
+        arguments[i + 1..].fill(0);
+    }
+    Ok(Equivalence::Equivalent { checked, exhausted })
+}
+
+/// Comparison of two functions on one argument tuple.
+enum Comparison {
+    /// functions give the same result.
+    Agree,
+    /// some execution ran out of steps.
+    Inconclusive,
+    /// functions give different results.
+    Disagree(Counterexample),
+}
+
+/// Compares results of two functions on given arguments.
+fn compare(
+    first: &RecurFunction,
+    second: &RecurFunction,
+    arguments: &[u32],
+    fuel: u64,
+) -> Comparison {
+    let first_evaluation = evaluate(first, arguments, fuel);
+    let second_evaluation = evaluate(second, arguments, fuel);
+    if !first_evaluation.is_conclusive() || !second_evaluation.is_conclusive() {
+        return Comparison::Inconclusive;
+    }
+    if first_evaluation == second_evaluation {
+        return Comparison::Agree;
+    }
+    Comparison::Disagree(Counterexample {
+        arguments: arguments.to_vec(),
+        first: first_evaluation,
+        second: second_evaluation,
+    })
+}
+
+/// Shrinks arguments on which functions disagree while they still disagree:
+/// every argument is replaced by zero, by its half or decreased by one.
+///
+/// # Arguments
+///
+/// * `counterexample` - arguments on which functions disagree with their results.
+/// * `disagree` - compares functions on given arguments, returns counterexample if they disagree.
+///
+/// # Returns
+///
+/// Minimal found counterexample.
+pub(crate) fn shrink(
+    mut counterexample: Counterexample,
+    disagree: impl Fn(&[u32]) -> Option<Counterexample>,
+) -> Counterexample {
+    let mut arguments = counterexample.arguments.clone();
+    let mut shrunk = true;
+    while shrunk {
+        shrunk = false;
+        for i in 0..arguments.len() {
+            let argument = arguments[i];
+            for candidate in [0, argument / 2, argument.saturating_sub(1)] {
+                if candidate >= argument {
+                    continue;
+                }
+                arguments[i] = candidate;
+                if let Some(smaller) = disagree(&arguments) {
+                    counterexample = smaller;
+                    shrunk = true;
+                    break;
+                }
+                arguments[i] = argument;
+            }
+        }
+    }
+    counterexample
+}
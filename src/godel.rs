@@ -0,0 +1,157 @@
+//! Gödel numbering of recursive functions.
+//!
+//! Every function term gets unique natural number. Successor is 0, other terms are numbered as
+//! 1 + 6 * payload + tag, where tag is number of function type and payload is built from numbers
+//! of inner terms and integers with Cantor pairing function. Lists of composition functions are numbered
+//! as 0 for empty list and 1 + pair(head, tail) for other lists. Functions referred by identifier are numbered as their definition.
+//!
+//! Numbering is bijection between natural numbers and terms whose arguments counts are not checked,
+//! so every well-formed function has unique number and some numbers don't decode into well-formed functions.
+
+use crate::{RecurFunction, RecurFunctionType};
+use num_bigint::BigUint;
+
+/// Natural number of arbitrary size.
+pub type BigNat = BigUint;
+
+/// Count of function types except successor.
+const TAGS_COUNT: u32 = 6;
+
+/// Computes Gödel number of recursive function.
+///
+/// # Arguments
+///
+/// * `function` - recursive function.
+///
+/// # Returns
+///
+/// Gödel number of function.
+pub fn encode(function: &RecurFunction) -> BigNat {
+    let (tag, payload) = match function.function_type() {
+        RecurFunctionType::Successor => return BigNat::ZERO,
+        RecurFunctionType::Zero(arguments_count) => (0u32, BigNat::from(*arguments_count)),
+        RecurFunctionType::Constant(arguments_count, number) => (
+            1,
+            pair(BigNat::from(*arguments_count), BigNat::from(*number)),
+        ),
+        RecurFunctionType::Projection(arguments_count, argument_number) => (
+            2,
+            pair(
+                BigNat::from(arguments_count - argument_number),
+                BigNat::from(argument_number - 1),
+            ),
+        ),
+        RecurFunctionType::Composition(base_function, functions) => {
+            let mut list = BigNat::ZERO;
+            for function in functions.iter().rev() {
+                list = pair(encode(function), list) + 1u32;
+            }
+            (3, pair(encode(base_function), list))
+        }
+        RecurFunctionType::Primitive(base_function, step_function) => {
+            (4, pair(encode(base_function), encode(step_function)))
+        }
+        RecurFunctionType::Minimization(base_function, max) => {
+            (5, pair(encode(base_function), BigNat::from(*max)))
+        }
+    };
+    payload * TAGS_COUNT + tag + 1u32
+}
+
+/// Decodes recursive function from its Gödel number checking arguments counts of all inner functions.
+///
+/// # Arguments
+///
+/// * `number` - Gödel number.
+///
+/// # Returns
+///
+/// Some(RecurFunction) if number encodes well-formed function, otherwise None.
+pub fn decode(number: &BigNat) -> Option<RecurFunction> {
+    if *number == BigNat::ZERO {
+        return RecurFunction::from_function_type(RecurFunctionType::Successor).ok();
+    }
+    let number = number - 1u32;
+    let tag = u32::try_from(&number % TAGS_COUNT).ok()?;
+    let payload = number / TAGS_COUNT;
+    let function_type = match tag {
+        0 => RecurFunctionType::Zero(u32::try_from(payload).ok()?),
+        1 => {
+            let (arguments_count, number) = unpair(&payload);
+            RecurFunctionType::Constant(
+                u32::try_from(arguments_count).ok()?,
+                u32::try_from(number).ok()?,
+            )
+        }
+        2 => {
+            let (difference, argument_number) = unpair(&payload);
+            let argument_number = u32::try_from(argument_number).ok()?.checked_add(1)?;
+            let arguments_count = argument_number.checked_add(u32::try_from(difference).ok()?)?;
+            RecurFunctionType::Projection(arguments_count, argument_number)
+        }
+        3 => {
+            let (base_number, mut list) = unpair(&payload);
+            let base_function = decode(&base_number)?;
+            let mut functions = Vec::new();
+            while list != BigNat::ZERO {
+                let (function_number, tail) = unpair(&(list - 1u32));
+                functions.push(decode(&function_number)?);
+                list = tail;
+            }
+            RecurFunctionType::Composition(Box::new(base_function), functions)
+        }
+        4 => {
+            let (base_number, step_number) = unpair(&payload);
+            RecurFunctionType::Primitive(
+                Box::new(decode(&base_number)?),
+                Box::new(decode(&step_number)?),
+            )
+        }
+        _ => {
+            let (base_number, max) = unpair(&payload);
+            RecurFunctionType::Minimization(
+                Box::new(decode(&base_number)?),
+                u32::try_from(max).ok()?,
+            )
+        }
+    };
+    RecurFunction::from_function_type(function_type).ok()
+}
+
+/// Enumerates well-formed functions with given arguments count in order of their Gödel numbers.
+///
+/// # Arguments
+///
+/// * `arguments_count` - arguments count of functions.
+/// * `count` - count of functions to enumerate.
+///
+/// # Returns
+///
+/// Gödel numbers with their functions.
+pub fn enumerate(arguments_count: u32, count: usize) -> Vec<(BigNat, RecurFunction)> {
+    let mut functions = Vec::new();
+    let mut number = BigNat::ZERO;
+    while functions.len() < count {
+        if let Some(function) = decode(&number) {
+            if function.arguments_count() == arguments_count {
+                functions.push((number.clone(), function));
+            }
+        }
+        number += 1u32;
+    }
+    functions
+}
+
+/// Cantor pairing function: (a + b)(a + b + 1) / 2 + b.
+fn pair(first: BigNat, second: BigNat) -> BigNat {
+    let sum = &first + &second;
+    (&sum * (&sum + 1u32)) / 2u32 + second
+}
+
+/// Inverse of Cantor pairing function.
+fn unpair(number: &BigNat) -> (BigNat, BigNat) {
+    let diagonal = ((number * 8u32 + 1u32).sqrt() - 1u32) / 2u32;
+    let second = number - (&diagonal * (&diagonal + 1u32)) / 2u32;
+    let first = diagonal - &second;
+    (first, second)
+}
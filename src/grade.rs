@@ -0,0 +1,277 @@
+//! Grading of submitted recursive functions against reference functions.
+//!
+//! Every function of reference is looked up in submission by its identifier, then its arguments count is checked,
+//! its results are compared with reference results using bounded equivalence checking
+//! and structural constraints are checked. Submitted function fails on arguments on which it runs out of steps
+//! while reference function gives result. Grades are reported in JSON.
+
+use crate::classify::classify;
+use crate::equiv::{
+    check_equivalence, evaluate, shrink, Counterexample, Equivalence, Evaluation, DEFAULT_FUEL,
+};
+use crate::graph::dependencies;
+use crate::RecurFunction;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Options of grading.
+pub struct GradeOptions {
+    /// max value of every argument used for comparison with reference.
+    pub up_to: u32,
+    /// max number of steps of every execution.
+    pub fuel: u64,
+    /// submitted functions must not use minimization.
+    pub forbid_minimization: bool,
+    /// identifiers which submitted functions can refer to directly, None if every identifier can be used.
+    pub allowed_identifiers: Option<Vec<String>>,
+}
+
+impl Default for GradeOptions {
+    fn default() -> Self {
+        GradeOptions {
+            up_to: 10,
+            fuel: DEFAULT_FUEL,
+            forbid_minimization: false,
+            allowed_identifiers: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Violation of structural constraint.
+pub enum Violation {
+    /// function uses minimization.
+    Minimization,
+    /// function uses identifier which is not allowed.
+    ForbiddenIdentifier(String),
+}
+
+impl fmt::Display for Violation {
+    /// Formats violation, for example: uses forbidden identifier addition.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::Minimization => write!(f, "uses minimization"),
+            Violation::ForbiddenIdentifier(identifier) => {
+                write!(f, "uses forbidden identifier {identifier}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Grade of one submitted function.
+pub struct FunctionGrade {
+    /// identifier of function.
+    pub identifier: String,
+    /// arguments count of reference function.
+    pub expected_arguments_count: u32,
+    /// arguments count of submitted function, None if function wasn't submitted.
+    pub arguments_count: Option<u32>,
+    /// result of comparison with reference function, None if function wasn't submitted or has wrong arguments count.
+    pub equivalence: Option<Equivalence>,
+    /// violated structural constraints.
+    pub violations: Vec<Violation>,
+}
+
+impl FunctionGrade {
+    /// Returns true if function has right arguments count, agrees with reference function and satisfies constraints.
+    /// Function which runs out of steps where reference function gives result doesn't agree with it.
+    pub fn passed(&self) -> bool {
+        self.arguments_count == Some(self.expected_arguments_count)
+            && matches!(self.equivalence, Some(Equivalence::Equivalent { .. }))
+            && self.violations.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Grades of all reference functions.
+pub struct GradeReport {
+    /// grades sorted by identifier.
+    pub grades: Vec<FunctionGrade>,
+}
+
+impl GradeReport {
+    /// Returns count of passed functions.
+    pub fn score(&self) -> usize {
+        self.grades.iter().filter(|grade| grade.passed()).count()
+    }
+
+    /// Returns count of graded functions.
+    pub fn max_score(&self) -> usize {
+        self.grades.len()
+    }
+
+    /// Formats report in JSON.
+    pub fn to_json(&self) -> String {
+        let grades: Vec<String> = self.grades.iter().map(grade_json).collect();
+        format!(
+            "{{\n  \"score\": {},\n  \"max_score\": {},\n  \"functions\": [{}\n  ]\n}}\n",
+            self.score(),
+            self.max_score(),
+            grades.join(",")
+        )
+    }
+}
+
+/// Grades submitted functions against reference functions.
+///
+/// # Arguments
+///
+/// * `reference` - parsed identifiers and their reference function.
+/// * `submission` - parsed identifiers and their submitted function.
+/// * `options` - options of grading.
+///
+/// # Returns
+///
+/// Report with grade of every reference function.
+pub fn grade(
+    reference: &HashMap<String, RecurFunction>,
+    submission: &HashMap<String, RecurFunction>,
+    options: &GradeOptions,
+) -> GradeReport {
+    let reference: BTreeMap<&String, &RecurFunction> = reference.iter().collect();
+    let mut grades = Vec::new();
+    for (identifier, reference_function) in reference {
+        let mut function_grade = FunctionGrade {
+            identifier: identifier.clone(),
+            expected_arguments_count: reference_function.arguments_count(),
+            arguments_count: None,
+            equivalence: None,
+            violations: Vec::new(),
+        };
+        let Some(function) = submission.get(identifier) else {
+            grades.push(function_grade);
+            continue;
+        };
+        function_grade.arguments_count = Some(function.arguments_count());
+        if function.arguments_count() == reference_function.arguments_count() {
+            function_grade.equivalence =
+                check_equivalence(reference_function, function, options.up_to, options.fuel)
+                    .ok()
+                    .map(|equivalence| match equivalence {
+                        Equivalence::Equivalent { exhausted, .. } if exhausted > 0 => {
+                            find_exhausted(reference_function, function, options)
+                                .map_or(equivalence, Equivalence::Counterexample)
+                        }
+                        equivalence => equivalence,
+                    });
+        }
+        if options.forbid_minimization && !classify(identifier, function).is_primitive_recursive() {
+            function_grade.violations.push(Violation::Minimization);
+        }
+        if let Some(allowed_identifiers) = &options.allowed_identifiers {
+            for used_identifier in dependencies(function) {
+                if !allowed_identifiers.contains(&used_identifier) {
+                    function_grade
+                        .violations
+                        .push(Violation::ForbiddenIdentifier(used_identifier));
+                }
+            }
+        }
+        grades.push(function_grade);
+    }
+    GradeReport { grades }
+}
+
+/// Finds minimal arguments on which reference function gives result but submitted function runs out of steps.
+///
+/// # Arguments
+///
+/// * `reference` - reference function.
+/// * `function` - submitted function with the same arguments count.
+/// * `options` - options of grading.
+///
+/// # Returns
+///
+/// Counterexample whose second result is exhausted, None if there are no such arguments.
+fn find_exhausted(
+    reference: &RecurFunction,
+    function: &RecurFunction,
+    options: &GradeOptions,
+) -> Option<Counterexample> {
+    let exhausted = |arguments: &[u32]| {
+        let first = evaluate(reference, arguments, options.fuel);
+        let second = evaluate(function, arguments, options.fuel);
+        (first.is_conclusive() && !second.is_conclusive()).then(|| Counterexample {
+            arguments: arguments.to_vec(),
+            first,
+            second,
+        })
+    };
+    let mut arguments = vec![0; reference.arguments_count() as usize];
+    loop {
+        if let Some(counterexample) = exhausted(&arguments) {
+            return Some(shrink(counterexample, exhausted));
+        }
+        let i = arguments
+            .iter()
+            .rposition(|argument| *argument < options.up_to)?;
+        arguments[i] += 1;
+        arguments[i + 1..].fill(0);
+    }
+}
+
+/// Formats grade of one function in JSON.
+fn grade_json(grade: &FunctionGrade) -> String {
+    let arguments_count = match grade.arguments_count {
+        Some(arguments_count) => arguments_count.to_string(),
+        None => "null".to_string(),
+    };
+    let (checked, exhausted, counterexample) = match &grade.equivalence {
+        Some(Equivalence::Equivalent { checked, exhausted }) => (
+            checked.to_string(),
+            exhausted.to_string(),
+            "null".to_string(),
+        ),
+        Some(Equivalence::Counterexample(counterexample)) => {
+            let arguments: Vec<String> = counterexample
+                .arguments
+                .iter()
+                .map(|argument| argument.to_string())
+                .collect();
+            (
+                "null".to_string(),
+                "null".to_string(),
+                format!(
+                    "{{ \"arguments\": [{}], \"expected\": {}, \"actual\": {} }}",
+                    arguments.join(", "),
+                    evaluation_json(&counterexample.first),
+                    evaluation_json(&counterexample.second)
+                ),
+            )
+        }
+        None => ("null".to_string(), "null".to_string(), "null".to_string()),
+    };
+    let violations: Vec<String> = grade
+        .violations
+        .iter()
+        .map(|violation| format!("\"{violation}\""))
+        .collect();
+    format!(
+        "
+    {{
+      \"identifier\": \"{}\",
+      \"passed\": {},
+      \"expected_arguments_count\": {},
+      \"arguments_count\": {arguments_count},
+      \"checked\": {checked},
+      \"exhausted\": {exhausted},
+      \"counterexample\": {counterexample},
+      \"violations\": [{}]
+    }}",
+        grade.identifier,
+        grade.passed(),
+        grade.expected_arguments_count,
+        violations.join(", ")
+    )
+}
+
+/// Formats evaluation in JSON, number if result is defined otherwise object with status undefined or exhausted.
+fn evaluation_json(evaluation: &Evaluation) -> String {
+    match evaluation {
+        Evaluation::Defined(number) => number.to_string(),
+        Evaluation::Undefined => "{ \"status\": \"undefined\" }".to_string(),
+        Evaluation::Exhausted => "{ \"status\": \"exhausted\" }".to_string(),
+    }
+}
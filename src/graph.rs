@@ -0,0 +1,276 @@
+//! Export of dependency graph of recursive function definitions in Graphviz DOT and Mermaid formats.
+
+use crate::classify::classify;
+use crate::{RecurFunction, RecurFunctionParseError, RecurFunctionType};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Fill colour of nodes which use minimization.
+const MINIMIZATION_COLOR: &str = "#f08080";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Formats of exported graphs.
+pub enum GraphFormat {
+    /// Graphviz DOT format.
+    Dot,
+    /// Mermaid flowchart format.
+    Mermaid,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+/// Options of dependency graph export.
+pub struct GraphOptions {
+    /// identifier of function whose dependencies should be shown, None to show all functions.
+    pub identifier: Option<String>,
+    /// colour functions which use minimization.
+    pub color_minimization: bool,
+}
+
+/// Collects identifiers which are directly used by recursive function.
+///
+/// # Arguments
+///
+/// * `function` - recursive function.
+///
+/// # Returns
+///
+/// Identifiers of functions which were referred in function definition.
+pub fn dependencies(function: &RecurFunction) -> BTreeSet<String> {
+    let mut identifiers = BTreeSet::new();
+    collect_dependencies(function, &mut identifiers);
+    identifiers
+}
+
+/// Walks through function and collects identifiers without going into referred functions.
+fn collect_dependencies(function: &RecurFunction, identifiers: &mut BTreeSet<String>) {
+    if let Some(identifier) = function.identifier() {
+        identifiers.insert(identifier.to_string());
+        return;
+    }
+    match function.function_type() {
+        RecurFunctionType::Zero(_)
+        | RecurFunctionType::Constant(_, _)
+        | RecurFunctionType::Successor
+        | RecurFunctionType::Projection(_, _) => {}
+        RecurFunctionType::Composition(base_function, functions) => {
+            collect_dependencies(base_function, identifiers);
+            for function in functions {
+                collect_dependencies(function, identifiers);
+            }
+        }
+        RecurFunctionType::Primitive(base_function, step_function) => {
+            collect_dependencies(base_function, identifiers);
+            collect_dependencies(step_function, identifiers);
+        }
+        RecurFunctionType::Minimization(base_function, _) => {
+            collect_dependencies(base_function, identifiers);
+        }
+    }
+}
+
+/// Builds dependency graph of parsed recursive functions.
+///
+/// # Arguments
+///
+/// * `identifier_functions` - parsed identifiers and their function.
+/// * `identifier` - identifier of function whose transitive dependencies should be included, None to include all functions.
+///
+/// # Returns
+///
+/// BTreeMap<String, BTreeSet<String>> where key is identifier and value is identifiers used by its function
+/// or RecurFunctionParseError wraped into Result if identifier is undefined.
+pub fn dependency_graph(
+    identifier_functions: &HashMap<String, RecurFunction>,
+    identifier: Option<&str>,
+) -> Result<BTreeMap<String, BTreeSet<String>>, RecurFunctionParseError> {
+    let mut graph = BTreeMap::new();
+    let Some(identifier) = identifier else {
+        for (identifier, function) in identifier_functions {
+            graph.insert(identifier.clone(), dependencies(function));
+        }
+        return Ok(graph);
+    };
+    let mut identifiers = vec![identifier.to_string()];
+    while let Some(identifier) = identifiers.pop() {
+        if graph.contains_key(&identifier) {
+            continue;
+        }
+        let function = identifier_functions.get(&identifier).ok_or(
+            RecurFunctionParseError::UndefinedIdentifier(identifier.clone()),
+        )?;
+        let function_dependencies = dependencies(function);
+        identifiers.extend(function_dependencies.iter().cloned());
+        graph.insert(identifier, function_dependencies);
+    }
+    Ok(graph)
+}
+
+/// Exports dependency graph of parsed recursive functions.
+///
+/// # Arguments
+///
+/// * `identifier_functions` - parsed identifiers and their function.
+/// * `format` - format of exported graph.
+/// * `options` - options of export.
+///
+/// # Returns
+///
+/// Graph text in given format or RecurFunctionParseError wraped into Result if identifier from options is undefined.
+pub fn export_dependency_graph(
+    identifier_functions: &HashMap<String, RecurFunction>,
+    format: GraphFormat,
+    options: &GraphOptions,
+) -> Result<String, RecurFunctionParseError> {
+    let graph = dependency_graph(identifier_functions, options.identifier.as_deref())?;
+    let minimization_identifiers: Vec<&String> = graph
+        .keys()
+        .filter(|identifier| {
+            options.color_minimization
+                && !classify(identifier, &identifier_functions[*identifier])
+                    .is_primitive_recursive()
+        })
+        .collect();
+    let mut output = String::new();
+    match format {
+        GraphFormat::Dot => {
+            output.push_str("digraph dependencies {\n");
+            for identifier in graph.keys() {
+                output.push_str(&format!("    \"{identifier}\";\n"));
+            }
+            for (identifier, function_dependencies) in graph.iter() {
+                for dependency in function_dependencies {
+                    output.push_str(&format!("    \"{identifier}\" -> \"{dependency}\";\n"));
+                }
+            }
+            for identifier in minimization_identifiers {
+                output.push_str(&format!(
+                    "    \"{identifier}\" [style=filled, fillcolor=\"{MINIMIZATION_COLOR}\"];\n"
+                ));
+            }
+            output.push_str("}\n");
+        }
+        GraphFormat::Mermaid => {
+            // node IDs are prefixed, so identifiers such as `end` can't clash with keywords of Mermaid
+            output.push_str("graph TD\n");
+            for identifier in graph.keys() {
+                output.push_str(&format!("    def_{identifier}[\"{identifier}\"]\n"));
+            }
+            for (identifier, function_dependencies) in graph.iter() {
+                for dependency in function_dependencies {
+                    output.push_str(&format!("    def_{identifier} --> def_{dependency}\n"));
+                }
+            }
+            if !minimization_identifiers.is_empty() {
+                output.push_str(&format!(
+                    "    classDef minimization fill:{MINIMIZATION_COLOR}\n"
+                ));
+                for identifier in minimization_identifiers {
+                    output.push_str(&format!("    class def_{identifier} minimization\n"));
+                }
+            }
+        }
+    }
+    Ok(output)
+}
+
+/// Exports syntax tree of recursive function in Graphviz DOT format, every node shows its arguments count.
+///
+/// # Arguments
+///
+/// * `identifier` - identifier of function, uses as graph name.
+/// * `function` - recursive function.
+/// * `expand_depth` - how many nested levels of functions referred by identifier should be expanded into their syntax trees.
+///
+/// # Returns
+///
+/// Graph text in DOT format.
+pub fn export_function_tree(
+    identifier: &str,
+    function: &RecurFunction,
+    expand_depth: u32,
+) -> String {
+    let mut output = format!("digraph \"{identifier}\" {{\n");
+    let mut nodes_count: usize = 0;
+    write_tree_node(function, expand_depth, &mut nodes_count, &mut output);
+    output.push_str("}\n");
+    output
+}
+
+/// Writes node of syntax tree with its subtrees.
+///
+/// # Arguments
+///
+/// * `function` - function of node.
+/// * `expand_depth` - how many nested levels of referred functions should be expanded.
+/// * `nodes_count` - count of written nodes, uses for naming nodes.
+/// * `output` - text of graph.
+///
+/// # Returns
+///
+/// Name of written node.
+fn write_tree_node(
+    function: &RecurFunction,
+    expand_depth: u32,
+    nodes_count: &mut usize,
+    output: &mut String,
+) -> String {
+    let node = format!("n{nodes_count}");
+    *nodes_count += 1;
+    let arguments = match function.arguments_count() {
+        1 => "1 argument".to_string(),
+        arguments_count => format!("{arguments_count} arguments"),
+    };
+    if let Some(identifier) = function.identifier() {
+        output.push_str(&format!(
+            "    {node} [label=\"{identifier}\\n{arguments}\", shape=box];\n"
+        ));
+        if expand_depth > 0 {
+            let mut expanded_function = function.clone();
+            expanded_function.identifier = None;
+            let child = write_tree_node(&expanded_function, expand_depth - 1, nodes_count, output);
+            output.push_str(&format!("    {node} -> {child} [style=dashed];\n"));
+        }
+        return node;
+    }
+    let children: Vec<(String, &RecurFunction)> = match function.function_type() {
+        RecurFunctionType::Zero(_)
+        | RecurFunctionType::Constant(_, _)
+        | RecurFunctionType::Successor
+        | RecurFunctionType::Projection(_, _) => {
+            output.push_str(&format!(
+                "    {node} [label=\"{}\\n{arguments}\"];\n",
+                function.function_type()
+            ));
+            return node;
+        }
+        RecurFunctionType::Composition(base_function, functions) => {
+            output.push_str(&format!(
+                "    {node} [label=\"composition\\n{arguments}\"];\n"
+            ));
+            let mut children = vec![("base".to_string(), base_function.as_ref())];
+            for (i, function) in functions.iter().enumerate() {
+                children.push(((i + 1).to_string(), function));
+            }
+            children
+        }
+        RecurFunctionType::Primitive(base_function, step_function) => {
+            output.push_str(&format!(
+                "    {node} [label=\"primitive\\n{arguments}\"];\n"
+            ));
+            vec![
+                ("base".to_string(), base_function.as_ref()),
+                ("step".to_string(), step_function.as_ref()),
+            ]
+        }
+        RecurFunctionType::Minimization(base_function, max) => {
+            output.push_str(&format!(
+                "    {node} [label=\"minimization up to {max}\\n{arguments}\"];\n"
+            ));
+            vec![("base".to_string(), base_function.as_ref())]
+        }
+    };
+    for (label, child_function) in children {
+        let child = write_tree_node(child_function, expand_depth, nodes_count, output);
+        output.push_str(&format!("    {node} -> {child} [label=\"{label}\"];\n"));
+    }
+    node
+}
@@ -0,0 +1,382 @@
+//! Estimation of growth rate of recursive functions: their level in Grzegorczyk hierarchy and execution steps bound.
+//!
+//! Values of functions are bounded by affine functions, polynomials, elementary functions (towers of exponents of fixed height)
+//! or functions of higher Grzegorczyk levels. Every primitive function which iterates its step function on previous value
+//! can raise the bound to the next class.
+
+use crate::{AbstractValue, RecurFunction, RecurFunctionType};
+use std::fmt;
+
+/// Grzegorczyk level from which function execution is expected to take enormous time.
+pub const ENORMOUS_RUNTIME_LEVEL: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Growth rate estimation of recursive function.
+pub struct GrowthEstimate {
+    /// nesting depth of primitive functions.
+    pub primitive_depth: u32,
+    /// level of Grzegorczyk hierarchy which contains function.
+    pub grzegorczyk_level: u32,
+    /// rough worst-case execution steps bound.
+    pub step_bound: StepBound,
+}
+
+impl GrowthEstimate {
+    /// Returns true if function execution is expected to take enormous time even on small arguments.
+    pub fn is_enormous(&self) -> bool {
+        self.grzegorczyk_level >= ENORMOUS_RUNTIME_LEVEL
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Rough worst-case execution steps bound as function of the largest argument n.
+pub enum StepBound {
+    /// steps count is bounded by polynomial of given degree.
+    Polynomial(u32),
+    /// steps count is bounded by 2 with given number of Knuth's up-arrows before n: 2^n, 2^^n and so on.
+    Arrow(u32),
+}
+
+impl fmt::Display for StepBound {
+    /// Formats bound, for example: O(n^2) or 2^^n.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StepBound::Polynomial(0) => write!(f, "O(1)"),
+            StepBound::Polynomial(1) => write!(f, "O(n)"),
+            StepBound::Polynomial(degree) => write!(f, "O(n^{degree})"),
+            StepBound::Arrow(arrows) => write!(f, "2{}n", "^".repeat(*arrows as usize)),
+        }
+    }
+}
+
+/// Estimates growth rate of recursive function.
+///
+/// # Arguments
+///
+/// * `function` - function to estimate.
+///
+/// # Returns
+///
+/// Growth rate estimation of function.
+pub fn estimate_growth(function: &RecurFunction) -> GrowthEstimate {
+    let growth = growth(function);
+    let grzegorczyk_level = growth.bound.level();
+    let step_bound = match growth.step_degree {
+        Some(degree) => StepBound::Polynomial(degree),
+        None => StepBound::Arrow(grzegorczyk_level.saturating_sub(2).max(1)),
+    };
+    GrowthEstimate {
+        primitive_depth: growth.primitive_depth,
+        grzegorczyk_level,
+        step_bound,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Bound of function values.
+enum ValueBound {
+    /// values are bounded by sum of arguments multiplied by coefficients and constant.
+    Affine(Vec<u64>, u64),
+    /// values are bounded by polynomial of given degree.
+    Polynomial(u32),
+    /// values are bounded by tower of exponents of fixed height.
+    Elementary,
+    /// values are bounded by function of given Grzegorczyk level which is greater than 3.
+    Level(u32),
+}
+
+impl ValueBound {
+    /// Returns level of Grzegorczyk hierarchy which contains functions with this bound.
+    fn level(&self) -> u32 {
+        match self {
+            ValueBound::Affine(coefficients, _) if coefficients.iter().sum::<u64>() <= 1 => 0,
+            ValueBound::Affine(_, _) => 1,
+            ValueBound::Polynomial(degree) if *degree <= 1 => 1,
+            ValueBound::Polynomial(_) => 2,
+            ValueBound::Elementary => 3,
+            ValueBound::Level(level) => *level,
+        }
+    }
+
+    /// Returns degree of polynomial which bounds values, None if values grow faster than polynomials.
+    fn degree(&self) -> Option<u32> {
+        match self {
+            ValueBound::Affine(coefficients, _) => {
+                Some(coefficients.iter().any(|coefficient| *coefficient > 0) as u32)
+            }
+            ValueBound::Polynomial(degree) => Some(*degree),
+            ValueBound::Elementary | ValueBound::Level(_) => None,
+        }
+    }
+
+    /// Returns the least bound which is greater than both bounds, keeps only polynomial degree if bounds are not affine.
+    fn max(self, other: ValueBound) -> ValueBound {
+        match (self.degree(), other.degree()) {
+            (Some(degree), Some(other_degree)) => ValueBound::Polynomial(degree.max(other_degree)),
+            _ => ValueBound::Level(self.level().max(other.level())).normalized(),
+        }
+    }
+
+    /// Returns bound of function which iterates function with this bound on its previous value.
+    fn iterated(&self) -> ValueBound {
+        match self {
+            ValueBound::Affine(_, _) | ValueBound::Polynomial(_) => ValueBound::Elementary,
+            ValueBound::Elementary => ValueBound::Level(4),
+            ValueBound::Level(level) => ValueBound::Level(level + 1),
+        }
+    }
+
+    /// Replaces levels of classes which have own variants by these variants.
+    fn normalized(self) -> ValueBound {
+        match self {
+            ValueBound::Level(level) if level <= 2 => ValueBound::Polynomial(level),
+            ValueBound::Level(3) => ValueBound::Elementary,
+            bound => bound,
+        }
+    }
+}
+
+/// Growth information of function.
+struct Growth {
+    /// bound of function values.
+    bound: ValueBound,
+    /// arguments which function uses.
+    used_arguments: Vec<bool>,
+    /// degree of polynomial which bounds execution steps, None if steps grow faster than polynomials.
+    step_degree: Option<u32>,
+    /// nesting depth of primitive functions.
+    primitive_depth: u32,
+}
+
+/// Computes growth information of function, functions with known constant or argument value are executed without steps.
+fn growth(function: &RecurFunction) -> Growth {
+    let arguments_count = function.arguments_count() as usize;
+    let growth = match function.function_type() {
+        RecurFunctionType::Zero(_) => argument_growth(arguments_count, 0, 0),
+        RecurFunctionType::Constant(_, number) => {
+            argument_growth(arguments_count, 0, *number as u64)
+        }
+        RecurFunctionType::Successor => argument_growth(arguments_count, 1, 1),
+        RecurFunctionType::Projection(_, argument_number) => {
+            argument_growth(arguments_count, *argument_number, 0)
+        }
+        RecurFunctionType::Composition(base_function, functions) => {
+            composition_growth(arguments_count, base_function, functions)
+        }
+        RecurFunctionType::Primitive(base_function, step_function) => {
+            primitive_growth(arguments_count, base_function, step_function)
+        }
+        RecurFunctionType::Minimization(base_function, max) => {
+            let base_growth = growth(base_function);
+            let mut used_arguments = base_growth.used_arguments;
+            used_arguments.resize(arguments_count, false);
+            Growth {
+                bound: ValueBound::Affine(vec![0; arguments_count], *max as u64),
+                used_arguments,
+                step_degree: base_growth.step_degree,
+                primitive_depth: base_growth.primitive_depth,
+            }
+        }
+    };
+    match function.abstract_value() {
+        AbstractValue::Constant(number) => Growth {
+            primitive_depth: growth.primitive_depth,
+            ..argument_growth(arguments_count, 0, *number as u64)
+        },
+        AbstractValue::Argument(argument_number) => Growth {
+            primitive_depth: growth.primitive_depth,
+            ..argument_growth(arguments_count, *argument_number, 0)
+        },
+        _ => growth,
+    }
+}
+
+/// Creates growth information of function which returns its argument increased by constant without steps.
+///
+/// # Arguments
+///
+/// * `arguments_count` - function arguments count.
+/// * `argument_number` - number of returned argument, 0 if function returns only constant.
+/// * `constant` - constant which is added to returned argument.
+///
+/// # Returns
+///
+/// Growth information of function.
+fn argument_growth(arguments_count: usize, argument_number: u32, constant: u64) -> Growth {
+    let used_arguments: Vec<bool> = (1..=arguments_count as u32)
+        .map(|i| i == argument_number)
+        .collect();
+    Growth {
+        bound: ValueBound::Affine(
+            used_arguments.iter().map(|used| *used as u64).collect(),
+            constant,
+        ),
+        used_arguments,
+        step_degree: Some(0),
+        primitive_depth: 0,
+    }
+}
+
+/// Computes growth information of composition function.
+fn composition_growth(
+    arguments_count: usize,
+    base_function: &RecurFunction,
+    functions: &[RecurFunction],
+) -> Growth {
+    let base_growth = growth(base_function);
+    let functions_growth: Vec<Growth> = functions.iter().map(growth).collect();
+    let mut used_arguments = vec![false; arguments_count];
+    for (i, function_growth) in functions_growth.iter().enumerate() {
+        if base_growth.used_arguments.get(i) == Some(&true) {
+            for (j, used) in function_growth.used_arguments.iter().enumerate() {
+                used_arguments[j] |= used;
+            }
+        }
+    }
+    let primitive_depth = functions_growth
+        .iter()
+        .map(|function_growth| function_growth.primitive_depth)
+        .fold(base_growth.primitive_depth, u32::max);
+    let functions_degree = functions_growth
+        .iter()
+        .map(|function_growth| function_growth.bound.degree())
+        .try_fold(1, |degree, function_degree| {
+            function_degree.map(|function_degree| function_degree.max(degree))
+        });
+    let step_degree = functions_growth
+        .iter()
+        .map(|function_growth| function_growth.step_degree)
+        .try_fold(
+            base_growth
+                .step_degree
+                .zip(functions_degree)
+                .map(|(step_degree, degree)| step_degree * degree),
+            |step_degree, function_step_degree| {
+                function_step_degree.map(|function_step_degree| {
+                    step_degree.map(|step_degree| step_degree.max(function_step_degree))
+                })
+            },
+        )
+        .flatten();
+    let bound = match &base_growth.bound {
+        ValueBound::Affine(base_coefficients, base_constant) => {
+            let mut coefficients = vec![0u64; arguments_count];
+            let mut constant = *base_constant;
+            let mut bound = None;
+            for (base_coefficient, function_growth) in
+                base_coefficients.iter().zip(functions_growth.iter())
+            {
+                match &function_growth.bound {
+                    ValueBound::Affine(function_coefficients, function_constant) => {
+                        for (j, function_coefficient) in function_coefficients.iter().enumerate() {
+                            coefficients[j] = coefficients[j].saturating_add(
+                                base_coefficient.saturating_mul(*function_coefficient),
+                            );
+                        }
+                        constant = constant
+                            .saturating_add(base_coefficient.saturating_mul(*function_constant));
+                    }
+                    function_bound if *base_coefficient > 0 => {
+                        bound = Some(
+                            bound
+                                .unwrap_or(ValueBound::Polynomial(1))
+                                .max(function_bound.clone()),
+                        );
+                    }
+                    _ => {}
+                }
+            }
+            bound.unwrap_or(ValueBound::Affine(coefficients, constant))
+        }
+        base_bound => match (base_bound.degree(), functions_degree) {
+            (Some(base_degree), Some(degree)) => ValueBound::Polynomial(base_degree * degree),
+            _ => functions_growth
+                .iter()
+                .fold(base_bound.clone(), |bound, function_growth| {
+                    bound.max(function_growth.bound.clone())
+                }),
+        },
+    };
+    Growth {
+        bound,
+        used_arguments,
+        step_degree,
+        primitive_depth,
+    }
+}
+
+/// Computes growth information of primitive function.
+fn primitive_growth(
+    arguments_count: usize,
+    base_function: &RecurFunction,
+    step_function: &RecurFunction,
+) -> Growth {
+    let base_growth = growth(base_function);
+    let step_growth = growth(step_function);
+    let base_arguments_count = arguments_count - 1;
+    let previous_value_used = step_growth.used_arguments.get(arguments_count) == Some(&true);
+    let mut used_arguments = vec![false; arguments_count];
+    for (j, used) in used_arguments.iter_mut().enumerate() {
+        *used = base_growth.used_arguments.get(j) == Some(&true)
+            || step_growth.used_arguments.get(j) == Some(&true);
+    }
+    used_arguments[base_arguments_count] = true;
+    let bound = match (&base_growth.bound, &step_growth.bound) {
+        (
+            ValueBound::Affine(base_coefficients, base_constant),
+            ValueBound::Affine(step_coefficients, step_constant),
+        ) => {
+            let previous_coefficient = step_coefficients.get(arguments_count).copied().unwrap_or(0);
+            let mut coefficients = vec![0u64; arguments_count];
+            for (j, coefficient) in coefficients.iter_mut().enumerate() {
+                *coefficient = base_coefficients.get(j).copied().unwrap_or(0);
+            }
+            let step_arguments_grow = step_coefficients
+                .iter()
+                .take(arguments_count)
+                .any(|coefficient| *coefficient > 0);
+            match previous_coefficient {
+                0 => {
+                    for (j, coefficient) in coefficients.iter_mut().enumerate() {
+                        *coefficient = coefficient
+                            .saturating_add(step_coefficients.get(j).copied().unwrap_or(0));
+                    }
+                    ValueBound::Affine(coefficients, base_constant.saturating_add(*step_constant))
+                }
+                1 if !step_arguments_grow => {
+                    coefficients[base_arguments_count] = *step_constant;
+                    ValueBound::Affine(coefficients, *base_constant)
+                }
+                1 => ValueBound::Polynomial(2),
+                _ => ValueBound::Elementary,
+            }
+        }
+        (base_bound, ValueBound::Affine(step_coefficients, _))
+            if step_coefficients.get(arguments_count).copied().unwrap_or(0) == 1 =>
+        {
+            base_bound.clone().max(ValueBound::Polynomial(2))
+        }
+        (base_bound, step_bound) if previous_value_used => {
+            base_bound.clone().max(step_bound.iterated())
+        }
+        (base_bound, step_bound) => base_bound.clone().max(step_bound.clone()),
+    };
+    let step_degree = match (
+        base_growth.step_degree,
+        step_growth.step_degree,
+        bound.degree(),
+    ) {
+        (Some(base_step_degree), Some(step_step_degree), Some(degree)) => {
+            Some(base_step_degree.max(1 + step_step_degree * degree.max(1)))
+        }
+        _ => None,
+    };
+    Growth {
+        bound,
+        used_arguments,
+        step_degree,
+        primitive_depth: base_growth
+            .primitive_depth
+            .max(step_growth.primitive_depth + 1),
+    }
+}
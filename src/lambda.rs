@@ -0,0 +1,602 @@
+//! Translation of recursive functions into untyped lambda calculus with Church numerals and normal-order reduction.
+//!
+//! Number n is Church numeral λf.λx.f (f ... (f x)) with n applications of f. Primitive functions iterate step function
+//! which passes counter and previous value to the next iteration, minimization functions search with fixed-point combinator
+//! and reduce to term without normal form when no value up to max is found.
+//! Reduction is lazy, so results of inner functions which can be undefined are reduced before they are passed on
+//! and translation of function is undefined exactly where function is undefined.
+
+use crate::{RecurFunction, RecurFunctionType};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Numbers which are greater than this number are built by doubling instead of writing all applications.
+const APPLICATIONS_MAX: u32 = 8;
+
+#[derive(Debug)]
+/// Terms of lambda calculus with variables as de Bruijn indices.
+/// Terms are cloned, compared and dropped without recursion, so deep terms like big numerals don't overflow stack.
+pub enum Term {
+    /// variable with count of abstractions between it and its binder.
+    Variable(usize),
+    /// abstraction with body.
+    Abstraction(Box<Term>),
+    /// application of function to argument.
+    Application(Box<Term>, Box<Term>),
+}
+
+/// Parts of term which were moved out of it.
+enum Parts {
+    /// variable with count of abstractions between it and its binder.
+    Variable(usize),
+    /// body of abstraction.
+    Abstraction(Term),
+    /// function and argument of application.
+    Application(Term, Term),
+}
+
+impl Term {
+    /// Moves parts out of term, term is left with variables in place of its parts.
+    fn into_parts(mut self) -> Parts {
+        match &mut self {
+            Term::Variable(index) => Parts::Variable(*index),
+            Term::Abstraction(body) => Parts::Abstraction(std::mem::replace(body, var(0))),
+            Term::Application(function, argument) => Parts::Application(
+                std::mem::replace(function, var(0)),
+                std::mem::replace(argument, var(0)),
+            ),
+        }
+    }
+
+    /// Formats term naming variable of every abstraction by its depth.
+    fn fmt_named(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        match self {
+            Term::Variable(index) => match depth.checked_sub(index + 1) {
+                Some(binder) => write!(f, "x{binder}"),
+                None => write!(f, "free{}", index - depth),
+            },
+            Term::Abstraction(body) => {
+                write!(f, "λx{depth}.")?;
+                body.fmt_named(f, depth + 1)
+            }
+            Term::Application(function, argument) => {
+                match function.as_ref() {
+                    Term::Abstraction(_) => {
+                        write!(f, "(")?;
+                        function.fmt_named(f, depth)?;
+                        write!(f, ")")?;
+                    }
+                    _ => function.fmt_named(f, depth)?,
+                }
+                write!(f, " ")?;
+                match argument.as_ref() {
+                    Term::Variable(_) => argument.fmt_named(f, depth),
+                    _ => {
+                        write!(f, "(")?;
+                        argument.fmt_named(f, depth)?;
+                        write!(f, ")")
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Clone for Term {
+    fn clone(&self) -> Self {
+        map_variables(self, |index, _| var(index))
+    }
+}
+
+impl PartialEq for Term {
+    fn eq(&self, other: &Self) -> bool {
+        let mut pairs = vec![(self, other)];
+        while let Some(pair) = pairs.pop() {
+            match pair {
+                (Term::Variable(first), Term::Variable(second)) if first == second => {}
+                (Term::Abstraction(first), Term::Abstraction(second)) => {
+                    pairs.push((first, second))
+                }
+                (
+                    Term::Application(first_function, first_argument),
+                    Term::Application(second_function, second_argument),
+                ) => {
+                    pairs.push((first_argument, second_argument));
+                    pairs.push((first_function, second_function));
+                }
+                _ => return false,
+            }
+        }
+        true
+    }
+}
+
+impl Drop for Term {
+    /// Drops inner terms one by one instead of recursively.
+    fn drop(&mut self) {
+        let mut terms: Vec<Term> = Vec::new();
+        let take = |term: &mut Term, terms: &mut Vec<Term>| match term {
+            Term::Variable(_) => {}
+            Term::Abstraction(body) => {
+                if !matches!(body.as_ref(), Term::Variable(_)) {
+                    terms.push(std::mem::replace(body, var(0)));
+                }
+            }
+            Term::Application(function, argument) => {
+                for inner in [function, argument] {
+                    if !matches!(inner.as_ref(), Term::Variable(_)) {
+                        terms.push(std::mem::replace(inner, var(0)));
+                    }
+                }
+            }
+        };
+        take(self, &mut terms);
+        while let Some(mut term) = terms.pop() {
+            take(&mut term, &mut terms);
+        }
+    }
+}
+
+impl fmt::Display for Term {
+    /// Formats term with named variables, for example: λx0.λx1.x0 (x0 x1).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_named(f, 0)
+    }
+}
+
+/// Creates variable.
+fn var(index: usize) -> Term {
+    Term::Variable(index)
+}
+
+/// Creates abstraction.
+fn lam(body: Term) -> Term {
+    Term::Abstraction(Box::new(body))
+}
+
+/// Creates abstractions with given count.
+fn lams(count: usize, body: Term) -> Term {
+    (0..count).fold(body, |body, _| lam(body))
+}
+
+/// Creates application of function to arguments.
+fn app(function: Term, arguments: Vec<Term>) -> Term {
+    arguments.into_iter().fold(function, |function, argument| {
+        Term::Application(Box::new(function), Box::new(argument))
+    })
+}
+
+/// Variables of abstractions with given count from the outermost one, inside given count of inner abstractions.
+fn vars(count: usize, inner: usize) -> Vec<Term> {
+    (0..count).map(|i| var(count - 1 - i + inner)).collect()
+}
+
+/// λa.λb.a
+fn church_true() -> Term {
+    lam(lam(var(1)))
+}
+
+/// λa.λb.b
+fn church_false() -> Term {
+    lam(lam(var(0)))
+}
+
+/// λn.λf.λx.f (n f x)
+fn successor() -> Term {
+    lam(lam(lam(app(
+        var(1),
+        vec![app(var(2), vec![var(1), var(0)])],
+    ))))
+}
+
+/// λn.λf.λx.n f (n f x)
+fn double() -> Term {
+    lam(lam(lam(app(
+        var(2),
+        vec![var(1), app(var(2), vec![var(1), var(0)])],
+    ))))
+}
+
+/// λn.λf.λx.n (λg.λh.h (g f)) (λu.x) (λu.u)
+fn predecessor() -> Term {
+    lam(lam(lam(app(
+        var(2),
+        vec![
+            lam(lam(app(var(0), vec![app(var(1), vec![var(3)])]))),
+            lam(var(1)),
+            lam(var(0)),
+        ],
+    ))))
+}
+
+/// λf.(λx.f (x x)) (λx.f (x x))
+fn fixed_point() -> Term {
+    let half = lam(app(var(1), vec![app(var(0), vec![var(0)])]));
+    lam(app(half.clone(), vec![half]))
+}
+
+/// (λx.x x) (λx.x x), term without normal form.
+fn omega() -> Term {
+    let half = lam(app(var(0), vec![var(0)]));
+    app(half.clone(), vec![half])
+}
+
+/// Selects the first term if number is zero and the second term otherwise.
+fn if_zero(number: Term, zero: Term, nonzero: Term) -> Term {
+    app(
+        number,
+        vec![lam(church_false()), church_true(), zero, nonzero],
+    )
+}
+
+/// Creates Church numeral.
+///
+/// # Arguments
+///
+/// * `number` - natural number.
+///
+/// # Returns
+///
+/// Church numeral in normal form.
+pub fn church_numeral(number: u32) -> Term {
+    lam(lam(
+        (0..number).fold(var(0), |term, _| app(var(1), vec![term]))
+    ))
+}
+
+/// Creates term which reduces to Church numeral, big numbers are built from their binary digits by doubling.
+fn numeral(number: u32) -> Term {
+    if number <= APPLICATIONS_MAX {
+        return church_numeral(number);
+    }
+    let doubled = app(double(), vec![numeral(number / 2)]);
+    match number % 2 {
+        0 => doubled,
+        _ => app(successor(), vec![doubled]),
+    }
+}
+
+/// Decodes Church numeral.
+///
+/// # Arguments
+///
+/// * `term` - term in normal form.
+///
+/// # Returns
+///
+/// Some(u32) if term is Church numeral, otherwise None.
+pub fn decode_numeral(term: &Term) -> Option<u32> {
+    let Term::Abstraction(body) = term else {
+        return None;
+    };
+    let Term::Abstraction(body) = body.as_ref() else {
+        return None;
+    };
+    let mut number: u32 = 0;
+    let mut current = body.as_ref();
+    while let Term::Application(function, argument) = current {
+        if **function != Term::Variable(1) {
+            return None;
+        }
+        number = number.checked_add(1)?;
+        current = argument;
+    }
+    match current {
+        Term::Variable(0) => Some(number),
+        _ => None,
+    }
+}
+
+/// Translates recursive function into closed term which takes Church numerals of arguments one by one.
+///
+/// # Arguments
+///
+/// * `function` - recursive function.
+///
+/// # Returns
+///
+/// Term which computes function.
+pub fn translate(function: &RecurFunction) -> Term {
+    let arguments_count = function.arguments_count() as usize;
+    match function.function_type() {
+        RecurFunctionType::Zero(_) => lams(arguments_count, church_numeral(0)),
+        RecurFunctionType::Constant(_, number) => lams(arguments_count, numeral(*number)),
+        RecurFunctionType::Successor => successor(),
+        RecurFunctionType::Projection(_, argument_number) => lams(
+            arguments_count,
+            var(arguments_count - *argument_number as usize),
+        ),
+        RecurFunctionType::Composition(base_function, functions) => {
+            let base_term = translate(base_function);
+            if base_function.arguments_count() == 0 {
+                return lams(arguments_count, base_term);
+            }
+            let partial: Vec<&RecurFunction> = functions
+                .iter()
+                .filter(|function| !function.abstract_value().is_total())
+                .collect();
+            let mut bound = partial.len();
+            let results = functions
+                .iter()
+                .map(|function| match function.abstract_value().is_total() {
+                    true => apply_arguments(
+                        translate(function),
+                        function,
+                        vars(arguments_count, partial.len()),
+                    ),
+                    false => {
+                        bound -= 1;
+                        var(bound)
+                    }
+                })
+                .collect();
+            let body = app(base_term, results);
+            let body = partial
+                .iter()
+                .enumerate()
+                .rev()
+                .fold(body, |body, (bound, function)| {
+                    strict(
+                        apply_arguments(
+                            translate(function),
+                            function,
+                            vars(arguments_count, bound),
+                        ),
+                        body,
+                    )
+                });
+            lams(arguments_count, body)
+        }
+        RecurFunctionType::Primitive(base_function, step_function) => {
+            let count = arguments_count - 1;
+            let strict_base = !base_function.abstract_value().is_total() as usize;
+            let next = apply_arguments(
+                translate(step_function),
+                step_function,
+                [vars(count, 4 + strict_base), vec![var(1), var(0)]].concat(),
+            );
+            let step = match step_function.abstract_value().is_total() {
+                true => app(var(2), vec![app(successor(), vec![var(1)]), next]),
+                false => strict(
+                    next,
+                    app(var(3), vec![app(successor(), vec![var(2)]), var(0)]),
+                ),
+            };
+            let base = apply_arguments(translate(base_function), base_function, vars(count, 1));
+            let iteration = |initial: Term| {
+                app(
+                    var(strict_base),
+                    vec![
+                        lam(lam(lam(step))),
+                        lam(lam(var(0))),
+                        church_numeral(0),
+                        initial,
+                    ],
+                )
+            };
+            lams(
+                arguments_count,
+                match strict_base {
+                    0 => iteration(base),
+                    _ => strict(base, iteration(var(0))),
+                },
+            )
+        }
+        RecurFunctionType::Minimization(base_function, max) => {
+            let search = lam(lam(lam(if_zero(
+                app(
+                    translate(base_function),
+                    [vars(arguments_count, 3), vec![var(1)]].concat(),
+                ),
+                var(1),
+                if_zero(
+                    var(0),
+                    omega(),
+                    app(
+                        var(2),
+                        vec![
+                            app(successor(), vec![var(1)]),
+                            app(predecessor(), vec![var(0)]),
+                        ],
+                    ),
+                ),
+            ))));
+            lams(
+                arguments_count,
+                app(
+                    fixed_point(),
+                    vec![search, church_numeral(0), numeral(*max)],
+                ),
+            )
+        }
+    }
+}
+
+/// Passes number to body as its variable with index 0 after number is reduced, so body is never reduced
+/// if number has no normal form. Number is rebuilt by successors, so it isn't reduced again where body uses it.
+fn strict(number: Term, body: Term) -> Term {
+    app(
+        number,
+        vec![
+            lam(lam(app(var(1), vec![app(successor(), vec![var(0)])]))),
+            lam(body),
+            church_numeral(0),
+        ],
+    )
+}
+
+/// Applies term of function to arguments unless function has no arguments and ignores them.
+fn apply_arguments(term: Term, function: &RecurFunction, arguments: Vec<Term>) -> Term {
+    match function.arguments_count() {
+        0 => term,
+        _ => app(term, arguments),
+    }
+}
+
+/// Reduces term to its normal form by normal-order strategy which always reduces the leftmost outermost redex.
+///
+/// # Arguments
+///
+/// * `term` - term to reduce.
+/// * `fuel` - max number of beta reductions.
+///
+/// # Returns
+///
+/// Some with normal form and count of beta reductions, None if reductions ran out.
+pub fn normalize(term: Term, fuel: u64) -> Option<(Term, u64)> {
+    let mut left = fuel;
+    let normal_form = reduce(term, &mut left)?;
+    Some((normal_form, fuel - left))
+}
+
+/// Runs term of function on given arguments.
+///
+/// # Arguments
+///
+/// * `term` - term which computes function.
+/// * `arguments` - arguments of function.
+/// * `fuel` - max number of beta reductions.
+///
+/// # Returns
+///
+/// Some with decoded result and count of beta reductions if term reduces to Church numeral,
+/// None if reductions ran out or result isn't numeral.
+pub fn run(term: &Term, arguments: &[u32], fuel: u64) -> Option<(u32, u64)> {
+    let applied = app(
+        term.clone(),
+        arguments
+            .iter()
+            .map(|argument| church_numeral(*argument))
+            .collect(),
+    );
+    let (normal_form, steps) = normalize(applied, fuel)?;
+    Some((decode_numeral(&normal_form)?, steps))
+}
+
+/// Steps of reduction to normal form which are kept on stack instead of recursion.
+enum Reduction {
+    /// reduce term.
+    Reduce(Term),
+    /// wrap the last reduced term into abstraction.
+    Abstraction,
+    /// apply the second last reduced term to the last one.
+    Application,
+}
+
+/// Reduces term to normal form.
+fn reduce(term: Term, fuel: &mut u64) -> Option<Term> {
+    let mut reductions = vec![Reduction::Reduce(term)];
+    let mut reduced: Vec<Term> = Vec::new();
+    while let Some(reduction) = reductions.pop() {
+        match reduction {
+            Reduction::Reduce(term) => match head_reduce(term, fuel)?.into_parts() {
+                Parts::Variable(index) => reduced.push(var(index)),
+                Parts::Abstraction(body) => {
+                    reductions.push(Reduction::Abstraction);
+                    reductions.push(Reduction::Reduce(body));
+                }
+                Parts::Application(function, argument) => {
+                    reductions.push(Reduction::Application);
+                    reductions.push(Reduction::Reduce(argument));
+                    reductions.push(Reduction::Reduce(function));
+                }
+            },
+            Reduction::Abstraction => {
+                let body = reduced.pop()?;
+                reduced.push(lam(body));
+            }
+            Reduction::Application => {
+                let argument = reduced.pop()?;
+                let function = reduced.pop()?;
+                reduced.push(Term::Application(Box::new(function), Box::new(argument)));
+            }
+        }
+    }
+    reduced.pop()
+}
+
+/// Reduces term to weak head normal form, which is abstraction or application of variable.
+fn head_reduce(term: Term, fuel: &mut u64) -> Option<Term> {
+    let mut term = term;
+    let mut arguments: Vec<Term> = Vec::new();
+    loop {
+        match term.into_parts() {
+            Parts::Application(function, argument) => {
+                arguments.push(argument);
+                term = function;
+            }
+            Parts::Abstraction(body) => match arguments.pop() {
+                Some(argument) => {
+                    *fuel = fuel.checked_sub(1)?;
+                    term = beta(&body, &argument);
+                }
+                None => return Some(lam(body)),
+            },
+            Parts::Variable(index) => {
+                term = var(index);
+                break;
+            }
+        }
+    }
+    while let Some(argument) = arguments.pop() {
+        term = Term::Application(Box::new(term), Box::new(argument));
+    }
+    Some(term)
+}
+
+/// Substitutes argument for variable of abstraction with given body.
+fn beta(body: &Term, argument: &Term) -> Term {
+    map_variables(body, |index, depth| match index.cmp(&depth) {
+        Ordering::Less => var(index),
+        Ordering::Equal => shift(argument, depth as isize),
+        Ordering::Greater => var(index - 1),
+    })
+}
+
+/// Shifts free variables by given amount.
+fn shift(term: &Term, amount: isize) -> Term {
+    map_variables(term, |index, depth| match index >= depth {
+        true => var(index.checked_add_signed(amount).expect("variable is bound")),
+        false => var(index),
+    })
+}
+
+/// Steps of rebuilding of term which are kept on stack instead of recursion.
+enum Rebuild<'a> {
+    /// rebuild term under given count of abstractions.
+    Visit(&'a Term, usize),
+    /// wrap the last rebuilt term into abstraction.
+    Abstraction,
+    /// apply the second last rebuilt term to the last one.
+    Application,
+}
+
+/// Rebuilds term replacing every variable by term computed from its index and count of abstractions above it.
+fn map_variables(term: &Term, replace: impl Fn(usize, usize) -> Term) -> Term {
+    let mut rebuilds = vec![Rebuild::Visit(term, 0)];
+    let mut rebuilt: Vec<Term> = Vec::new();
+    while let Some(rebuild) = rebuilds.pop() {
+        match rebuild {
+            Rebuild::Visit(Term::Variable(index), depth) => rebuilt.push(replace(*index, depth)),
+            Rebuild::Visit(Term::Abstraction(body), depth) => {
+                rebuilds.push(Rebuild::Abstraction);
+                rebuilds.push(Rebuild::Visit(body, depth + 1));
+            }
+            Rebuild::Visit(Term::Application(function, argument), depth) => {
+                rebuilds.push(Rebuild::Application);
+                rebuilds.push(Rebuild::Visit(argument, depth));
+                rebuilds.push(Rebuild::Visit(function, depth));
+            }
+            Rebuild::Abstraction => {
+                let body = rebuilt.pop().expect("body is rebuilt");
+                rebuilt.push(lam(body));
+            }
+            Rebuild::Application => {
+                let argument = rebuilt.pop().expect("argument is rebuilt");
+                let function = rebuilt.pop().expect("function is rebuilt");
+                rebuilt.push(Term::Application(Box::new(function), Box::new(argument)));
+            }
+        }
+    }
+    rebuilt.pop().expect("term is rebuilt")
+}
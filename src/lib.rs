@@ -3,6 +3,23 @@ use pest_derive::Parser;
 use std::collections::HashMap;
 use thiserror::Error;
 
+pub mod classify;
+pub mod codegen;
+pub mod differential;
+mod display;
+pub mod enumerate;
+pub mod equiv;
+pub mod godel;
+pub mod grade;
+pub mod graph;
+pub mod growth;
+pub mod lambda;
+pub mod lint;
+pub mod loop_program;
+pub mod notation;
+pub mod synth;
+pub mod turing;
+pub mod urm;
 
 #[derive(Parser)]
 #[grammar = "./grammar.pest"]
@@ -45,6 +62,18 @@ pub enum RecurFunctionParseError {
     #[error("Identifier already exists: {0}")]
     /// Error which signals that identifier already exists.
     IdentifierAlreadyExists(String),
+    #[error("Arguments count cannot be inferred from context: {0}")]
+    /// Error which signals that omitted arguments count cannot be inferred from context.
+    AmbiguousArgumentsCount(String),
+    #[error("Identifier is not allowed: {0}")]
+    /// Error which signals that identifier is not in list of allowed identifiers.
+    ForbiddenIdentifier(String),
+    #[error("Function is not allowed: {0}")]
+    /// Error which signals that primitive or minimization function is used where it is forbidden.
+    ForbiddenFunction(String),
+    #[error("Examples have different identifiers: {0} and {1}")]
+    /// Error which signals that examples of one function have different identifiers.
+    ExampleIdentifierMismatch(String, String),
     #[error("Undefined rule while parsing: {0}")]
     /// Error which signals that rule is undefined.
     UndefinedRule(String),
@@ -53,8 +82,10 @@ pub enum RecurFunctionParseError {
 #[derive(Debug, Clone, PartialEq)]
 /// Types of recursive functions.
 pub enum RecurFunctionType {
-    /// zero function.
-    Zero,
+    /// zero function with arguments count.
+    Zero(u32),
+    /// constant function with arguments count and number.
+    Constant(u32, u32),
     /// succesor function.
     Successor,
     /// projection function with arguments count and argument number.
@@ -68,14 +99,282 @@ pub enum RecurFunctionType {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+/// Abstract value of recursive function which is computed when function is parsed.
+pub enum AbstractValue {
+    /// function is defined on all arguments and always returns this number.
+    Constant(u32),
+    /// function is defined on all arguments and always returns argument with this number.
+    Argument(u32),
+    /// function is defined on all arguments.
+    Total,
+    /// function can be undefined on some arguments.
+    Partial,
+}
+
+impl AbstractValue {
+    /// Returns number if abstract value is constant otherwise None.
+    pub fn constant(&self) -> Option<u32> {
+        match self {
+            AbstractValue::Constant(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    /// Returns true if function with this abstract value is defined on all arguments.
+    pub fn is_total(&self) -> bool {
+        *self != AbstractValue::Partial
+    }
+}
+
+#[derive(Debug, Clone)]
 /// Struct which describes recursive function.
 pub struct RecurFunction {
     /// function type.
     function_type: RecurFunctionType,
     /// function arguments count.
     arguments_count: u32,
-    /// contains number if function is number otherwise None.
-    number: Option<u32>,
+    /// abstract value of function, contains number if function is constant.
+    abstract_value: AbstractValue,
+    /// identifier which was used to refer to this function, None if function was written in place.
+    identifier: Option<String>,
+}
+
+impl PartialEq for RecurFunction {
+    /// Compares functions by their definitions, identifier which was used to refer to function is ignored.
+    fn eq(&self, other: &Self) -> bool {
+        self.function_type == other.function_type
+            && self.arguments_count == other.arguments_count
+            && self.abstract_value == other.abstract_value
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+/// Restrictions of identifiers and functions which can be used in function definitions.
+pub struct Restrictions {
+    /// identifiers which can be referred, None if every defined identifier can be referred.
+    pub allowed_identifiers: Option<Vec<String>>,
+    /// forbid primitive functions.
+    pub forbid_primitive: bool,
+    /// forbid minimization functions.
+    pub forbid_minimization: bool,
+}
+
+/// Max number of execution steps which can be used for computing constant of composition function.
+const CONSTANT_FOLDING_FUEL: u64 = 100_000;
+
+impl RecurFunction {
+    /// Creates recursive function and computes its abstract value from abstract values of inner functions.
+    ///
+    /// # Arguments
+    ///
+    /// * `function_type` - function type.
+    /// * `arguments_count` - function arguments count.
+    ///
+    /// # Returns
+    ///
+    /// The recursive function.
+    fn new(function_type: RecurFunctionType, arguments_count: u32) -> RecurFunction {
+        let abstract_value = match &function_type {
+            RecurFunctionType::Zero(_) => AbstractValue::Constant(0),
+            RecurFunctionType::Constant(_, number) => AbstractValue::Constant(*number),
+            RecurFunctionType::Successor => AbstractValue::Total,
+            RecurFunctionType::Projection(_, argument_number) => {
+                AbstractValue::Argument(*argument_number)
+            }
+            RecurFunctionType::Composition(base_function, functions) => {
+                composition_abstract_value(base_function, functions)
+            }
+            RecurFunctionType::Primitive(base_function, step_function) => {
+                primitive_abstract_value(base_function, step_function)
+            }
+            RecurFunctionType::Minimization(base_function, _) => {
+                minimization_abstract_value(base_function)
+            }
+        };
+        RecurFunction {
+            function_type,
+            arguments_count,
+            abstract_value,
+            identifier: None,
+        }
+    }
+
+    /// Returns function type.
+    pub fn function_type(&self) -> &RecurFunctionType {
+        &self.function_type
+    }
+
+    /// Returns identifier which was used to refer to this function, None if function was written in place.
+    pub fn identifier(&self) -> Option<&str> {
+        self.identifier.as_deref()
+    }
+
+    /// Returns abstract value of function.
+    pub fn abstract_value(&self) -> &AbstractValue {
+        &self.abstract_value
+    }
+
+    /// Returns number if function is constant otherwise None.
+    pub fn constant(&self) -> Option<u32> {
+        self.abstract_value.constant()
+    }
+
+    /// Returns function arguments count.
+    pub fn arguments_count(&self) -> u32 {
+        self.arguments_count
+    }
+
+    /// Checks if function can be base function of primitive function with base of given arguments count.
+    /// Base function without arguments is a constant h(x̄, 0) = c which ignores all given arguments.
+    fn fits_base_arguments_count(&self, arguments_count: u32) -> bool {
+        self.arguments_count == arguments_count || self.arguments_count == 0
+    }
+
+    /// Creates recursive function from function type computing its arguments count by the same rules as parser.
+    ///
+    /// # Arguments
+    ///
+    /// * `function_type` - function type.
+    ///
+    /// # Returns
+    ///
+    /// The recursive function or RecurFunctionParseError wraped in Result if arguments counts of inner functions don't fit.
+    pub fn from_function_type(
+        function_type: RecurFunctionType,
+    ) -> Result<RecurFunction, RecurFunctionParseError> {
+        let function_str = function_type.to_string();
+        let arguments_count = match &function_type {
+            RecurFunctionType::Zero(arguments_count)
+            | RecurFunctionType::Constant(arguments_count, _) => *arguments_count,
+            RecurFunctionType::Successor => 1,
+            RecurFunctionType::Projection(arguments_count, argument_number) => {
+                if *argument_number == 0 {
+                    return Err(RecurFunctionParseError::InvalidProjectionArgumentNumber(
+                        function_str,
+                    ));
+                }
+                if arguments_count < argument_number {
+                    return Err(RecurFunctionParseError::InvalidArgumentsCount(function_str));
+                }
+                *arguments_count
+            }
+            RecurFunctionType::Composition(base_function, functions) => {
+                let arguments_count = functions
+                    .first()
+                    .map_or(0, |function| function.arguments_count);
+                if functions
+                    .iter()
+                    .any(|function| function.arguments_count != arguments_count)
+                {
+                    return Err(RecurFunctionParseError::InvalidArgumentsCount(function_str));
+                }
+                if base_function.arguments_count != functions.len() as u32 {
+                    return Err(RecurFunctionParseError::InvalidCompositionFunctionsCount(
+                        function_str,
+                    ));
+                }
+                arguments_count
+            }
+            RecurFunctionType::Primitive(base_function, step_function) => {
+                if step_function.arguments_count < 2 {
+                    return Err(RecurFunctionParseError::InvalidPrimitiveStepArgumentsCount(
+                        function_str,
+                    ));
+                }
+                if !base_function.fits_base_arguments_count(step_function.arguments_count - 2) {
+                    return Err(RecurFunctionParseError::InvalidPrimitiveBaseArgumentsCount(
+                        function_str,
+                    ));
+                }
+                step_function.arguments_count - 1
+            }
+            RecurFunctionType::Minimization(base_function, _) => {
+                if base_function.arguments_count == 0 {
+                    return Err(RecurFunctionParseError::InvalidArgumentsCount(function_str));
+                }
+                base_function.arguments_count - 1
+            }
+        };
+        Ok(RecurFunction::new(function_type, arguments_count))
+    }
+}
+
+/// Computes abstract value of composition function.
+/// Composition is constant if base function is constant or if all functions are constant and base function on their values could be computed.
+fn composition_abstract_value(
+    base_function: &RecurFunction,
+    functions: &[RecurFunction],
+) -> AbstractValue {
+    if functions
+        .iter()
+        .any(|function| !function.abstract_value.is_total())
+    {
+        return AbstractValue::Partial;
+    }
+    match &base_function.abstract_value {
+        AbstractValue::Constant(number) => AbstractValue::Constant(*number),
+        AbstractValue::Argument(argument_number) => functions
+            .get(*argument_number as usize - 1)
+            .map_or(AbstractValue::Total, |function| {
+                function.abstract_value.clone()
+            }),
+        abstract_value => {
+            let constants: Option<Vec<u32>> = functions
+                .iter()
+                .map(|function| function.abstract_value.constant())
+                .collect();
+            if let Some(constants) = constants {
+                let mut fuel = CONSTANT_FOLDING_FUEL;
+                match execute_with_fuel(base_function, &constants, &mut fuel) {
+                    Some(Some(number)) => return AbstractValue::Constant(number),
+                    Some(None) => return AbstractValue::Partial,
+                    None => {}
+                }
+            }
+            abstract_value.clone()
+        }
+    }
+}
+
+/// Computes abstract value of primitive function.
+/// Primitive is constant if step function returns previous value or the same constant as base function.
+fn primitive_abstract_value(
+    base_function: &RecurFunction,
+    step_function: &RecurFunction,
+) -> AbstractValue {
+    match (&base_function.abstract_value, &step_function.abstract_value) {
+        (AbstractValue::Partial, _) | (_, AbstractValue::Partial) => AbstractValue::Partial,
+        (base_value, AbstractValue::Argument(argument_number))
+            if *argument_number == step_function.arguments_count =>
+        {
+            base_value.clone()
+        }
+        (AbstractValue::Constant(base_number), AbstractValue::Constant(step_number))
+            if base_number == step_number =>
+        {
+            AbstractValue::Constant(*base_number)
+        }
+        (AbstractValue::Argument(base_number), AbstractValue::Argument(step_number))
+            if base_number == step_number =>
+        {
+            AbstractValue::Argument(*base_number)
+        }
+        _ => AbstractValue::Total,
+    }
+}
+
+/// Computes abstract value of minimization function.
+/// Minimization is constant zero if base function returns zero on first try.
+fn minimization_abstract_value(base_function: &RecurFunction) -> AbstractValue {
+    match &base_function.abstract_value {
+        AbstractValue::Constant(0) => AbstractValue::Constant(0),
+        AbstractValue::Argument(argument_number)
+            if *argument_number == base_function.arguments_count =>
+        {
+            AbstractValue::Constant(0)
+        }
+        _ => AbstractValue::Partial,
+    }
 }
 
 /// Struct which describes query.
@@ -86,6 +385,18 @@ pub struct Query {
     arguments: Vec<u32>,
 }
 
+impl Query {
+    /// Returns identifier of function to use for query.
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Returns arguments for function.
+    pub fn arguments(&self) -> &[u32] {
+        &self.arguments
+    }
+}
+
 /// Parses recursive function pair into RecurFunction struct.
 ///
 /// # Arguments
@@ -99,35 +410,93 @@ pub struct Query {
 pub fn parse_recur_function(
     pair: pest::iterators::Pair<Rule>,
     identifier_functions: &HashMap<String, RecurFunction>,
+) -> Result<RecurFunction, RecurFunctionParseError> {
+    parse_recur_function_with_arguments_count(pair, identifier_functions, None)
+}
+
+/// Parses recursive function pair into RecurFunction struct using arguments count expected by context.
+/// Omitted arguments count of projection or constant is taken from context,
+/// context is given by composition functions, primitive base and step functions, minimization base function.
+///
+/// # Arguments
+///
+/// * `pair` - pest pair that is recursive function.
+/// * `identifier_functions` - parsed identifiers and their function, uses for checking existing functions.
+/// * `expected_arguments_count` - arguments count expected by context, None if context doesn't define it.
+///
+/// # Returns
+///
+/// The parsed recursive function or RecurFunctionParseError wraped in Result.
+pub fn parse_recur_function_with_arguments_count(
+    pair: pest::iterators::Pair<Rule>,
+    identifier_functions: &HashMap<String, RecurFunction>,
+    expected_arguments_count: Option<u32>,
+) -> Result<RecurFunction, RecurFunctionParseError> {
+    parse_restricted_recur_function(
+        pair,
+        identifier_functions,
+        &Restrictions::default(),
+        expected_arguments_count,
+    )
+}
+
+/// Parses recursive function pair into RecurFunction struct checking that it uses only allowed identifiers and functions.
+///
+/// # Arguments
+///
+/// * `pair` - pest pair that is recursive function.
+/// * `identifier_functions` - parsed identifiers and their function, uses for checking existing functions.
+/// * `restrictions` - restrictions of identifiers and functions which can be used.
+///
+/// # Returns
+///
+/// The parsed recursive function or RecurFunctionParseError wraped in Result.
+pub fn parse_recur_function_with_restrictions(
+    pair: pest::iterators::Pair<Rule>,
+    identifier_functions: &HashMap<String, RecurFunction>,
+    restrictions: &Restrictions,
+) -> Result<RecurFunction, RecurFunctionParseError> {
+    parse_restricted_recur_function(pair, identifier_functions, restrictions, None)
+}
+
+/// Parses recursive function pair into RecurFunction struct using arguments count expected by context
+/// and checking that it uses only allowed identifiers and functions.
+///
+/// # Arguments
+///
+/// * `pair` - pest pair that is recursive function.
+/// * `identifier_functions` - parsed identifiers and their function, uses for checking existing functions.
+/// * `restrictions` - restrictions of identifiers and functions which can be used.
+/// * `expected_arguments_count` - arguments count expected by context, None if context doesn't define it.
+///
+/// # Returns
+///
+/// The parsed recursive function or RecurFunctionParseError wraped in Result.
+fn parse_restricted_recur_function(
+    pair: pest::iterators::Pair<Rule>,
+    identifier_functions: &HashMap<String, RecurFunction>,
+    restrictions: &Restrictions,
+    expected_arguments_count: Option<u32>,
 ) -> Result<RecurFunction, RecurFunctionParseError> {
     let pair_str = pair.as_str();
     match pair.as_rule() {
-        Rule::zero => Ok(RecurFunction {
-            function_type: RecurFunctionType::Zero,
-            arguments_count: 1,
-            number: Some(0),
-        }),
-        Rule::successor => Ok(RecurFunction {
-            function_type: RecurFunctionType::Successor,
-            arguments_count: 1,
-            number: None,
-        }),
+        Rule::zero => {
+            let arguments_count: u32 = match pair.into_inner().next() {
+                Some(inner_pair) => inner_pair.as_str().parse::<u32>()?,
+                // zero without context has no arguments
+                None => expected_arguments_count.unwrap_or(0),
+            };
+            Ok(RecurFunction::new(
+                RecurFunctionType::Zero(arguments_count),
+                arguments_count,
+            ))
+        }
+        Rule::successor => Ok(RecurFunction::new(RecurFunctionType::Successor, 1)),
         Rule::projection => {
-            let mut inner_pairs = pair.into_inner();
-            let arguments_count: u32 = inner_pairs
-                .next()
-                .ok_or(RecurFunctionParseError::IntegerExpected(
-                    pair_str.to_string(),
-                ))?
-                .as_str()
-                .parse::<u32>()?;
-            let argument_number: u32 = inner_pairs
-                .next()
-                .ok_or(RecurFunctionParseError::IntegerExpected(
-                    pair_str.to_string(),
-                ))?
-                .as_str()
-                .parse::<u32>()?;
+            let (arguments_count, argument_number) = parse_indexed_integers(pair)?;
+            let arguments_count: u32 = arguments_count.or(expected_arguments_count).ok_or(
+                RecurFunctionParseError::AmbiguousArgumentsCount(pair_str.to_string()),
+            )?;
             if argument_number == 0 {
                 return Err(RecurFunctionParseError::InvalidProjectionArgumentNumber(
                     pair_str.to_string(),
@@ -138,117 +507,184 @@ pub fn parse_recur_function(
                     pair_str.to_string(),
                 ));
             }
-            Ok(RecurFunction {
-                function_type: RecurFunctionType::Projection(arguments_count, argument_number),
+            Ok(RecurFunction::new(
+                RecurFunctionType::Projection(arguments_count, argument_number),
+                arguments_count,
+            ))
+        }
+        Rule::constant => {
+            let (arguments_count, number) = parse_indexed_integers(pair)?;
+            let arguments_count: u32 = arguments_count.or(expected_arguments_count).ok_or(
+                RecurFunctionParseError::AmbiguousArgumentsCount(pair_str.to_string()),
+            )?;
+            Ok(RecurFunction::new(
+                RecurFunctionType::Constant(arguments_count, number),
+                arguments_count,
+            ))
+        }
+        Rule::integer => {
+            let number: u32 = pair_str.parse::<u32>()?;
+            // literal without context has no arguments
+            let arguments_count: u32 = expected_arguments_count.unwrap_or(0);
+            Ok(RecurFunction::new(
+                RecurFunctionType::Constant(arguments_count, number),
                 arguments_count,
-                number: None,
-            })
+            ))
         }
         Rule::composition => {
             let mut inner_pairs = pair.into_inner();
-            let base_function = parse_recur_function(
-                inner_pairs
-                    .next()
-                    .ok_or(RecurFunctionParseError::FunctionExpected(
-                        pair_str.to_string(),
-                    ))?,
+            let base_pair = inner_pairs
+                .next()
+                .ok_or(RecurFunctionParseError::FunctionExpected(
+                    pair_str.to_string(),
+                ))?;
+            let inner_pairs: Vec<pest::iterators::Pair<Rule>> = inner_pairs.collect();
+            let base_function = parse_restricted_recur_function(
+                base_pair,
                 identifier_functions,
+                restrictions,
+                Some(inner_pairs.len() as u32),
             )?;
+            let mut parsed_functions: Vec<Option<RecurFunction>> = Vec::new();
+            let mut arguments_count: Option<u32> = None;
+            for inner_pair in inner_pairs.iter() {
+                let function = match parse_restricted_recur_function(
+                    inner_pair.clone(),
+                    identifier_functions,
+                    restrictions,
+                    expected_arguments_count,
+                ) {
+                    Ok(function) => function,
+                    Err(RecurFunctionParseError::AmbiguousArgumentsCount(_)) => {
+                        parsed_functions.push(None);
+                        continue;
+                    }
+                    Err(error) => return Err(error),
+                };
+                arguments_count = arguments_count.max(Some(function.arguments_count));
+                parsed_functions.push(Some(function));
+            }
+            let arguments_count = match (arguments_count, inner_pairs.is_empty()) {
+                (Some(arguments_count), _) => arguments_count,
+                (None, true) => 0,
+                (None, false) => {
+                    return Err(RecurFunctionParseError::AmbiguousArgumentsCount(
+                        pair_str.to_string(),
+                    ))
+                }
+            };
             let mut functions: Vec<RecurFunction> = Vec::new();
-            let mut arguments_count: u32 = 0;
-            for inner_pair in inner_pairs {
-                let function = parse_recur_function(inner_pair, identifier_functions)?;
-                if arguments_count == 0 {
-                    arguments_count = function.arguments_count;
-                } else if arguments_count != function.arguments_count {
+            for (inner_pair, function) in inner_pairs.into_iter().zip(parsed_functions) {
+                // functions without context, such as literals, are parsed again with arguments count of others
+                let function = match function {
+                    Some(function) if function.arguments_count == arguments_count => function,
+                    _ => parse_restricted_recur_function(
+                        inner_pair,
+                        identifier_functions,
+                        restrictions,
+                        Some(arguments_count),
+                    )?,
+                };
+                if function.arguments_count != arguments_count {
                     return Err(RecurFunctionParseError::InvalidArgumentsCount(
                         pair_str.to_string(),
                     ));
                 }
                 functions.push(function);
             }
-            if functions.len() != base_function.arguments_count as usize {
+            if base_function.arguments_count != functions.len() as u32 {
                 return Err(RecurFunctionParseError::InvalidCompositionFunctionsCount(
                     pair_str.to_string(),
                 ));
             }
-            if arguments_count == 1
-                && functions.len() == 1
-                && functions[0].number.is_some()
-                && base_function.function_type == RecurFunctionType::Successor
-            {
-                let number = functions[0].number.unwrap() + 1;
-                return Ok(RecurFunction {
-                    function_type: RecurFunctionType::Composition(
-                        Box::new(base_function),
-                        functions,
-                    ),
-                    arguments_count,
-                    number: Some(number),
-                });
-            }
-            Ok(RecurFunction {
-                function_type: RecurFunctionType::Composition(Box::new(base_function), functions),
+            Ok(RecurFunction::new(
+                RecurFunctionType::Composition(Box::new(base_function), functions),
                 arguments_count,
-                number: None,
-            })
+            ))
         }
         Rule::primitive => {
+            if restrictions.forbid_primitive {
+                return Err(RecurFunctionParseError::ForbiddenFunction(
+                    pair_str.to_string(),
+                ));
+            }
             let mut inner_pairs = pair.into_inner();
-            let base_function = parse_recur_function(
-                inner_pairs
-                    .next()
-                    .ok_or(RecurFunctionParseError::FunctionExpected(
-                        pair_str.to_string(),
-                    ))?,
-                identifier_functions,
-            )?;
-            let step_function = parse_recur_function(
-                inner_pairs
-                    .next()
-                    .ok_or(RecurFunctionParseError::FunctionExpected(
-                        pair_str.to_string(),
-                    ))?,
+            let base_pair = inner_pairs
+                .next()
+                .ok_or(RecurFunctionParseError::FunctionExpected(
+                    pair_str.to_string(),
+                ))?;
+            let step_pair = inner_pairs
+                .next()
+                .ok_or(RecurFunctionParseError::FunctionExpected(
+                    pair_str.to_string(),
+                ))?;
+            let (base_function, step_function) = match parse_restricted_recur_function(
+                step_pair.clone(),
                 identifier_functions,
-            )?;
+                restrictions,
+                expected_arguments_count.map(|arguments_count| arguments_count + 1),
+            ) {
+                Ok(step_function) if step_function.arguments_count > 0 => {
+                    let base_function = parse_restricted_recur_function(
+                        base_pair,
+                        identifier_functions,
+                        restrictions,
+                        step_function.arguments_count.checked_sub(2),
+                    )?;
+                    (base_function, step_function)
+                }
+                // step function without context, such as zero, gets arguments count from base function
+                Ok(_) | Err(RecurFunctionParseError::AmbiguousArgumentsCount(_)) => {
+                    let base_function = parse_restricted_recur_function(
+                        base_pair,
+                        identifier_functions,
+                        restrictions,
+                        expected_arguments_count
+                            .and_then(|arguments_count| arguments_count.checked_sub(1)),
+                    )?;
+                    let step_function = parse_restricted_recur_function(
+                        step_pair,
+                        identifier_functions,
+                        restrictions,
+                        Some(base_function.arguments_count + 2),
+                    )?;
+                    (base_function, step_function)
+                }
+                Err(error) => return Err(error),
+            };
             if step_function.arguments_count < 2 {
                 return Err(RecurFunctionParseError::InvalidPrimitiveStepArgumentsCount(
                     pair_str.to_string(),
                 ));
             }
-            if step_function.arguments_count == 2
-                && (base_function.arguments_count != 1 || base_function.number.is_none())
-            {
-                return Err(RecurFunctionParseError::InvalidPrimitiveBaseArgumentsCount(
-                    pair_str.to_string(),
-                ));
-            }
-            if step_function.arguments_count > 2
-                && base_function.arguments_count != step_function.arguments_count - 2
-            {
+            if !base_function.fits_base_arguments_count(step_function.arguments_count - 2) {
                 return Err(RecurFunctionParseError::InvalidPrimitiveBaseArgumentsCount(
                     pair_str.to_string(),
                 ));
             }
             let arguments_count = step_function.arguments_count - 1;
-            Ok(RecurFunction {
-                function_type: RecurFunctionType::Primitive(
-                    Box::new(base_function),
-                    Box::new(step_function),
-                ),
+            Ok(RecurFunction::new(
+                RecurFunctionType::Primitive(Box::new(base_function), Box::new(step_function)),
                 arguments_count,
-                number: None,
-            })
+            ))
         }
         Rule::minimization => {
+            if restrictions.forbid_minimization {
+                return Err(RecurFunctionParseError::ForbiddenFunction(
+                    pair_str.to_string(),
+                ));
+            }
             let mut inner_pairs = pair.into_inner();
-            let base_function = parse_recur_function(
+            let base_function = parse_restricted_recur_function(
                 inner_pairs
                     .next()
                     .ok_or(RecurFunctionParseError::FunctionExpected(
                         pair_str.to_string(),
                     ))?,
                 identifier_functions,
+                restrictions,
+                expected_arguments_count.map(|arguments_count| arguments_count + 1),
             )?;
             let max: u32 = inner_pairs
                 .next()
@@ -257,35 +693,42 @@ pub fn parse_recur_function(
                 ))?
                 .as_str()
                 .parse::<u32>()?;
-            if base_function.arguments_count <= 1 {
+            if base_function.arguments_count == 0 {
                 return Err(RecurFunctionParseError::InvalidArgumentsCount(
                     pair_str.to_string(),
                 ));
             }
             let arguments_count = base_function.arguments_count - 1;
-            Ok(RecurFunction {
-                function_type: RecurFunctionType::Minimization(Box::new(base_function), max),
+            Ok(RecurFunction::new(
+                RecurFunctionType::Minimization(Box::new(base_function), max),
                 arguments_count,
-                number: None,
-            })
+            ))
         }
         Rule::identifier => {
             let identifier: String = pair.as_str().to_string();
-            let function = identifier_functions
+            let mut function = identifier_functions
                 .get(&identifier)
                 .ok_or(RecurFunctionParseError::UndefinedIdentifier(
                     pair.as_str().to_string(),
                 ))?
                 .clone();
+            if let Some(allowed_identifiers) = &restrictions.allowed_identifiers {
+                if !allowed_identifiers.contains(&identifier) {
+                    return Err(RecurFunctionParseError::ForbiddenIdentifier(identifier));
+                }
+            }
+            function.identifier = Some(identifier);
             Ok(function)
         }
-        Rule::recursive_function => parse_recur_function(
+        Rule::recursive_function => parse_restricted_recur_function(
             pair.into_inner()
                 .next()
                 .ok_or(RecurFunctionParseError::FunctionExpected(
                     pair_str.to_string(),
                 ))?,
             identifier_functions,
+            restrictions,
+            expected_arguments_count,
         ),
         _ => Err(RecurFunctionParseError::UndefinedRule(
             pair.as_str().to_string(),
@@ -293,6 +736,32 @@ pub fn parse_recur_function(
     }
 }
 
+/// Parses integers of projection or constant pair, first of them is arguments count which can be omitted.
+///
+/// # Arguments
+///
+/// * `pair` - pest pair that is projection or constant.
+///
+/// # Returns
+///
+/// Arguments count if it is given and second integer or RecurFunctionParseError wraped in Result.
+fn parse_indexed_integers(
+    pair: pest::iterators::Pair<Rule>,
+) -> Result<(Option<u32>, u32), RecurFunctionParseError> {
+    let pair_str = pair.as_str();
+    let mut integers: Vec<u32> = Vec::new();
+    for inner_pair in pair.into_inner() {
+        integers.push(inner_pair.as_str().parse::<u32>()?);
+    }
+    match integers[..] {
+        [arguments_count, number] => Ok((Some(arguments_count), number)),
+        [number] => Ok((None, number)),
+        _ => Err(RecurFunctionParseError::IntegerExpected(
+            pair_str.to_string(),
+        )),
+    }
+}
+
 /// Parses recursive functions input into HashMap<String, RecurFunction> where key is identifier and value is its recursive function.
 ///
 /// # Arguments
@@ -304,6 +773,23 @@ pub fn parse_recur_function(
 /// HashMap<String, RecurFunction> where key is identifier and value is its recursive function or RecurFunctionParseError wraped into Result.
 pub fn parse_recur_functions(
     input: &str,
+) -> Result<HashMap<String, RecurFunction>, RecurFunctionParseError> {
+    parse_recur_functions_with_restrictions(input, &HashMap::new())
+}
+
+/// Parses recursive functions input into HashMap<String, RecurFunction> checking that restricted definitions use only allowed identifiers and functions.
+///
+/// # Arguments
+///
+/// * `input` - string which includes recursive functions.
+/// * `identifier_restrictions` - identifiers of restricted definitions and their restrictions, other definitions aren't restricted.
+///
+/// # Returns
+///
+/// HashMap<String, RecurFunction> where key is identifier and value is its recursive function or RecurFunctionParseError wraped into Result.
+pub fn parse_recur_functions_with_restrictions(
+    input: &str,
+    identifier_restrictions: &HashMap<String, Restrictions>,
 ) -> Result<HashMap<String, RecurFunction>, RecurFunctionParseError> {
     let got = RecurFunctionGrammar::parse(Rule::functions, input);
     let mut inner_pairs = match got {
@@ -318,6 +804,9 @@ pub fn parse_recur_functions(
         if inner_pair.as_rule() == Rule::EOI {
             break;
         }
+        if inner_pair.as_rule() == Rule::allow {
+            continue;
+        }
         let identifier: String = match inner_pair.as_rule() {
             Rule::identifier => inner_pair.as_str().to_string(),
             _ => {
@@ -333,7 +822,13 @@ pub fn parse_recur_functions(
             .next()
             .ok_or(RecurFunctionParseError::FunctionExpected(input.to_string()))?;
         let recur_function: RecurFunction = match inner_pair.as_rule() {
-            Rule::recursive_function => parse_recur_function(inner_pair, &identifier_functions)?,
+            Rule::recursive_function => parse_recur_function_with_restrictions(
+                inner_pair,
+                &identifier_functions,
+                identifier_restrictions
+                    .get(&identifier)
+                    .unwrap_or(&Restrictions::default()),
+            )?,
             _ => {
                 return Err(RecurFunctionParseError::FunctionExpected(
                     inner_pair.as_str().to_string(),
@@ -342,6 +837,14 @@ pub fn parse_recur_functions(
         };
         identifier_functions.insert(identifier, recur_function);
     }
+    if let Some(identifier) = identifier_restrictions
+        .keys()
+        .find(|identifier| !identifier_functions.contains_key(*identifier))
+    {
+        return Err(RecurFunctionParseError::UndefinedIdentifier(
+            identifier.clone(),
+        ));
+    }
     Ok(identifier_functions)
 }
 
@@ -403,12 +906,7 @@ pub fn parse_query(
         };
         arguments.push(integer);
     }
-    if function.number.is_some() && arguments.is_empty() {
-        return Ok(Query {
-            identifier,
-            arguments,
-        });
-    } else if function.arguments_count != arguments.len() as u32 {
+    if function.arguments_count != arguments.len() as u32 {
         return Err(RecurFunctionParseError::InvalidArgumentsCount(
             input.to_string(),
         ));
@@ -430,8 +928,16 @@ pub fn parse_query(
 ///
 /// Some(u32) if result is defined otherwise None.
 pub fn execute(function: &RecurFunction, arguments: &Vec<u32>) -> Option<u32> {
+    match function.abstract_value {
+        AbstractValue::Constant(number) => return Some(number),
+        AbstractValue::Argument(argument_number) => {
+            return arguments.get(argument_number as usize - 1).copied()
+        }
+        _ => {}
+    }
     match &function.function_type {
-        RecurFunctionType::Zero => Some(0),
+        RecurFunctionType::Zero(_) => Some(0),
+        RecurFunctionType::Constant(_, number) => Some(*number),
         RecurFunctionType::Successor => Some(arguments.first()? + 1),
         RecurFunctionType::Projection(_, argument_number) => {
             let res = arguments.get(*argument_number as usize - 1)?;
@@ -445,14 +951,12 @@ pub fn execute(function: &RecurFunction, arguments: &Vec<u32>) -> Option<u32> {
             execute(base_function, &functions_results)
         }
         RecurFunctionType::Primitive(base_function, step_function) => {
-            let mut res: u32;
             let mut arguments = arguments.clone();
             let max: u32 = arguments.pop()?;
-            if let Some(number) = base_function.number {
-                res = number;
-            } else {
-                res = execute(base_function, &arguments)?;
-            }
+            let mut res: u32 = match base_function.arguments_count {
+                0 => execute(base_function, &Vec::new())?,
+                _ => execute(base_function, &arguments)?,
+            };
             for i in 0..max {
                 let mut new_arguments = arguments.clone();
                 new_arguments.push(i);
@@ -489,8 +993,89 @@ pub fn execute_query(
     identifier_functions: &HashMap<String, RecurFunction>,
 ) -> Option<u32> {
     let function: &RecurFunction = identifier_functions.get(&query.identifier)?;
-    if let Some(number) = function.number {
+    if let Some(number) = function.constant() {
         return Some(number);
     }
     execute(function, &query.arguments)
 }
+
+/// Executes given recursive function on given arguments using limited number of steps.
+///
+/// # Arguments
+///
+/// * `function` - function to execute.
+/// * `arguments` - arguments to use for calculations.
+/// * `fuel` - number of steps left, every executed function uses one step.
+///
+/// # Returns
+///
+/// Some(Some(u32)) if result is defined, Some(None) if result is undefined, None if steps ran out or number overflowed.
+pub fn execute_with_fuel(
+    function: &RecurFunction,
+    arguments: &[u32],
+    fuel: &mut u64,
+) -> Option<Option<u32>> {
+    *fuel = fuel.checked_sub(1)?;
+    match function.abstract_value {
+        AbstractValue::Constant(number) => return Some(Some(number)),
+        AbstractValue::Argument(argument_number) => {
+            return Some(arguments.get(argument_number as usize - 1).copied())
+        }
+        _ => {}
+    }
+    match &function.function_type {
+        RecurFunctionType::Zero(_) => Some(Some(0)),
+        RecurFunctionType::Constant(_, number) => Some(Some(*number)),
+        RecurFunctionType::Successor => match arguments.first() {
+            Some(argument) => Some(Some(argument.checked_add(1)?)),
+            None => Some(None),
+        },
+        RecurFunctionType::Projection(_, argument_number) => {
+            Some(arguments.get(*argument_number as usize - 1).copied())
+        }
+        RecurFunctionType::Composition(base_function, functions) => {
+            let mut functions_results: Vec<u32> = Vec::<u32>::new();
+            for func in functions {
+                match execute_with_fuel(func, arguments, fuel)? {
+                    Some(number) => functions_results.push(number),
+                    None => return Some(None),
+                }
+            }
+            execute_with_fuel(base_function, &functions_results, fuel)
+        }
+        RecurFunctionType::Primitive(base_function, step_function) => {
+            let Some((max, arguments)) = arguments.split_last() else {
+                return Some(None);
+            };
+            let base_arguments = match base_function.arguments_count {
+                0 => &[],
+                _ => arguments,
+            };
+            let Some(mut res) = execute_with_fuel(base_function, base_arguments, fuel)? else {
+                return Some(None);
+            };
+            for i in 0..*max {
+                let mut new_arguments = arguments.to_vec();
+                new_arguments.push(i);
+                new_arguments.push(res);
+                match execute_with_fuel(step_function, &new_arguments, fuel)? {
+                    Some(number) => res = number,
+                    None => return Some(None),
+                }
+            }
+            Some(Some(res))
+        }
+        RecurFunctionType::Minimization(base_function, max) => {
+            for i in 0..=*max {
+                let mut new_arguments = arguments.to_vec();
+                new_arguments.push(i);
+                match execute_with_fuel(base_function, &new_arguments, fuel)? {
+                    Some(0) => return Some(Some(i)),
+                    Some(_) => {}
+                    None => return Some(None),
+                }
+            }
+            Some(None)
+        }
+    }
+}
@@ -0,0 +1,304 @@
+//! Lints of parsed recursive functions: unused definitions, unused arguments
+//! and primitive step functions which ignore recursion counter or previous value.
+//!
+//! Lints of definition can be allowed by annotation before it, for example: `@allow(unused, unused_argument) f = ...;`.
+
+use crate::graph::dependencies;
+use crate::{
+    parse_recur_function, AbstractValue, RecurFunction, RecurFunctionGrammar,
+    RecurFunctionParseError, RecurFunctionType, Rule,
+};
+use pest::iterators::Pair;
+use pest::Parser;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Kinds of lints.
+pub enum LintKind {
+    /// definition isn't used by other definitions or queries.
+    UnusedDefinition,
+    /// function never reads argument with this number.
+    UnusedArgument(u32),
+    /// step function of primitive function ignores recursion counter.
+    UnusedCounter,
+    /// step function of primitive function ignores previous value.
+    UnusedPreviousValue,
+}
+
+impl LintKind {
+    /// Returns name of lint which is used in allow annotations.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LintKind::UnusedDefinition => "unused",
+            LintKind::UnusedArgument(_) => "unused_argument",
+            LintKind::UnusedCounter => "unused_counter",
+            LintKind::UnusedPreviousValue => "unused_previous",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Warning found by lint pass.
+pub struct Lint {
+    /// kind of lint.
+    pub kind: LintKind,
+    /// identifier of definition which contains warned code.
+    pub identifier: String,
+    /// line of warned code in source, starts from 1.
+    pub line: usize,
+    /// column of warned code in source, starts from 1.
+    pub column: usize,
+}
+
+impl fmt::Display for Lint {
+    /// Formats lint, for example: 3:1: warning[unused]: function `f` is never used.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: warning[{}]: ",
+            self.line,
+            self.column,
+            self.kind.name()
+        )?;
+        match &self.kind {
+            LintKind::UnusedDefinition => write!(f, "function `{}` is never used", self.identifier),
+            LintKind::UnusedArgument(argument_number) => write!(
+                f,
+                "function `{}` never reads argument {argument_number}",
+                self.identifier
+            ),
+            LintKind::UnusedCounter => write!(
+                f,
+                "step function in `{}` ignores recursion counter",
+                self.identifier
+            ),
+            LintKind::UnusedPreviousValue => write!(
+                f,
+                "step function in `{}` ignores previous value",
+                self.identifier
+            ),
+        }
+    }
+}
+
+/// Runs lint pass over recursive functions input, every definition is parsed once together with its source span.
+///
+/// # Arguments
+///
+/// * `input` - string which includes recursive functions.
+/// * `used_identifiers` - identifiers of functions which are used by queries.
+///
+/// # Returns
+///
+/// Lints sorted by their position in source or RecurFunctionParseError wraped into Result.
+pub fn lint(
+    input: &str,
+    used_identifiers: &[String],
+) -> Result<Vec<Lint>, RecurFunctionParseError> {
+    let inner_pairs = RecurFunctionGrammar::parse(Rule::functions, input)
+        .map_err(|e| RecurFunctionParseError::UndefinedRule(e.to_string()))?
+        .next()
+        .ok_or(RecurFunctionParseError::UndefinedRule(input.to_string()))?
+        .into_inner();
+    let mut identifier_functions: HashMap<String, RecurFunction> = HashMap::new();
+    let mut definitions: Vec<(BTreeSet<String>, Pair<Rule>, Pair<Rule>)> = Vec::new();
+    let mut allowed: BTreeSet<String> = BTreeSet::new();
+    let mut identifier_pair: Option<Pair<Rule>> = None;
+    for inner_pair in inner_pairs {
+        match inner_pair.as_rule() {
+            Rule::allow => {
+                allowed.extend(
+                    inner_pair
+                        .into_inner()
+                        .map(|pair| pair.as_str().to_string()),
+                );
+            }
+            Rule::identifier => identifier_pair = Some(inner_pair),
+            Rule::recursive_function => {
+                let identifier_pair =
+                    identifier_pair
+                        .take()
+                        .ok_or(RecurFunctionParseError::IdentifierExpected(
+                            inner_pair.as_str().to_string(),
+                        ))?;
+                let identifier = identifier_pair.as_str().to_string();
+                if identifier_functions.contains_key(&identifier) {
+                    return Err(RecurFunctionParseError::IdentifierAlreadyExists(identifier));
+                }
+                let function = parse_recur_function(inner_pair.clone(), &identifier_functions)?;
+                identifier_functions.insert(identifier, function);
+                definitions.push((std::mem::take(&mut allowed), identifier_pair, inner_pair));
+            }
+            _ => {}
+        }
+    }
+    let mut used: BTreeSet<String> = used_identifiers.iter().cloned().collect();
+    for function in identifier_functions.values() {
+        used.extend(dependencies(function));
+    }
+    let mut lints: Vec<Lint> = Vec::new();
+    for (allowed, identifier_pair, function_pair) in definitions {
+        let identifier = identifier_pair.as_str().to_string();
+        let function = &identifier_functions[&identifier];
+        let (line, column) = identifier_pair.line_col();
+        let mut definition_lints = Vec::new();
+        if !used.contains(&identifier) {
+            definition_lints.push(Lint {
+                kind: LintKind::UnusedDefinition,
+                identifier: identifier.clone(),
+                line,
+                column,
+            });
+        }
+        for (i, is_used) in used_arguments(function).into_iter().enumerate() {
+            if !is_used {
+                definition_lints.push(Lint {
+                    kind: LintKind::UnusedArgument(i as u32 + 1),
+                    identifier: identifier.clone(),
+                    line,
+                    column,
+                });
+            }
+        }
+        lint_steps(&identifier, function, function_pair, &mut definition_lints);
+        lints.extend(
+            definition_lints
+                .into_iter()
+                .filter(|lint| !allowed.contains(lint.kind.name())),
+        );
+    }
+    Ok(lints)
+}
+
+/// Computes which arguments can be read by recursive function.
+///
+/// # Arguments
+///
+/// * `function` - recursive function.
+///
+/// # Returns
+///
+/// Vector with value for every argument, true if function can read argument.
+pub fn used_arguments(function: &RecurFunction) -> Vec<bool> {
+    let arguments_count = function.arguments_count() as usize;
+    match function.abstract_value() {
+        AbstractValue::Constant(_) => return vec![false; arguments_count],
+        AbstractValue::Argument(argument_number) => {
+            let mut used = vec![false; arguments_count];
+            if let Some(is_used) = used.get_mut(*argument_number as usize - 1) {
+                *is_used = true;
+            }
+            return used;
+        }
+        _ => {}
+    }
+    match function.function_type() {
+        RecurFunctionType::Zero(_) | RecurFunctionType::Constant(_, _) => {
+            vec![false; arguments_count]
+        }
+        RecurFunctionType::Successor => vec![true],
+        RecurFunctionType::Projection(_, argument_number) => {
+            let mut used = vec![false; arguments_count];
+            used[*argument_number as usize - 1] = true;
+            used
+        }
+        RecurFunctionType::Composition(base_function, functions) => {
+            let mut used = vec![false; arguments_count];
+            for (function, base_used) in functions.iter().zip(used_arguments(base_function)) {
+                if !base_used {
+                    continue;
+                }
+                for (is_used, function_used) in used.iter_mut().zip(used_arguments(function)) {
+                    *is_used |= function_used;
+                }
+            }
+            used
+        }
+        RecurFunctionType::Primitive(base_function, step_function) => {
+            let mut used = vec![false; arguments_count];
+            for (is_used, base_used) in used.iter_mut().zip(used_arguments(base_function)) {
+                *is_used |= base_used;
+            }
+            for (is_used, step_used) in used.iter_mut().zip(used_arguments(step_function)) {
+                *is_used |= step_used;
+            }
+            used[arguments_count - 1] = true;
+            used
+        }
+        RecurFunctionType::Minimization(base_function, _) => {
+            let mut used = used_arguments(base_function);
+            used.truncate(arguments_count);
+            used
+        }
+    }
+}
+
+/// Walks through pest pair of function definition together with parsed function
+/// and collects lints of primitive step functions written in definition.
+///
+/// # Arguments
+///
+/// * `identifier` - identifier of definition.
+/// * `function` - parsed function of pair.
+/// * `pair` - pest pair that is recursive function.
+/// * `lints` - collected lints.
+fn lint_steps(identifier: &str, function: &RecurFunction, pair: Pair<Rule>, lints: &mut Vec<Lint>) {
+    let pair = match pair.as_rule() {
+        Rule::recursive_function => match pair.into_inner().next() {
+            Some(pair) => pair,
+            None => return,
+        },
+        _ => pair,
+    };
+    if function.identifier().is_some() {
+        return;
+    }
+    let inner_pairs: Vec<Pair<Rule>> = pair.into_inner().collect();
+    match function.function_type() {
+        RecurFunctionType::Zero(_)
+        | RecurFunctionType::Constant(_, _)
+        | RecurFunctionType::Successor
+        | RecurFunctionType::Projection(_, _) => {}
+        RecurFunctionType::Composition(base_function, functions) => {
+            let inner_functions = std::iter::once(base_function.as_ref()).chain(functions.iter());
+            for (function, pair) in inner_functions.zip(inner_pairs) {
+                lint_steps(identifier, function, pair, lints);
+            }
+        }
+        RecurFunctionType::Primitive(base_function, step_function) => {
+            let mut inner_pairs = inner_pairs.into_iter();
+            let (Some(base_pair), Some(step_pair)) = (inner_pairs.next(), inner_pairs.next())
+            else {
+                return;
+            };
+            let (line, column) = step_pair.line_col();
+            let step_used = used_arguments(step_function);
+            if let [.., counter_used, previous_used] = step_used[..] {
+                if !counter_used {
+                    lints.push(Lint {
+                        kind: LintKind::UnusedCounter,
+                        identifier: identifier.to_string(),
+                        line,
+                        column,
+                    });
+                }
+                if !previous_used {
+                    lints.push(Lint {
+                        kind: LintKind::UnusedPreviousValue,
+                        identifier: identifier.to_string(),
+                        line,
+                        column,
+                    });
+                }
+            }
+            lint_steps(identifier, base_function, base_pair, lints);
+            lint_steps(identifier, step_function, step_pair, lints);
+        }
+        RecurFunctionType::Minimization(base_function, _) => {
+            if let Some(pair) = inner_pairs.into_iter().next() {
+                lint_steps(identifier, base_function, pair, lints);
+            }
+        }
+    }
+}
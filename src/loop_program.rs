@@ -0,0 +1,758 @@
+//! Translation of recursive functions into LOOP and WHILE programs, their interpretation
+//! and translation of LOOP programs back into recursive functions.
+//!
+//! Programs have registers x0, x1, ... which contain natural numbers. Program gets arguments in registers x1, ..., xk,
+//! other registers contain zero, result is in register x0. Primitive functions become LOOP statements
+//! which repeat their body as many times as register contained before the first repetition,
+//! minimization functions become WHILE statements which repeat their body until register contains zero.
+//! Programs without WHILE statements compute exactly primitive recursive functions.
+
+use crate::{parse_recur_functions, RecurFunction, RecurFunctionParseError, RecurFunctionType};
+use std::collections::BTreeSet;
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+/// Errors which can apear when translating program into recursive function.
+pub enum LoopTranslationError {
+    #[error("Program cannot be translated into recursive function: {0}")]
+    /// Error which signals that program uses statements which have no recursive function counterpart.
+    UntranslatableProgram(String),
+    #[error("Invalid translated function: {0}")]
+    /// Error which signals that translated function is not well-formed.
+    InvalidFunction(#[from] RecurFunctionParseError),
+}
+
+/// Constants which are greater than this number are built by doubling instead of successive increments.
+const INCREMENTS_MAX: u32 = 8;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Statements of LOOP and WHILE programs.
+pub enum Statement {
+    /// xi := 0.
+    Zero(usize),
+    /// xi := xi + 1.
+    Successor(usize),
+    /// xi := xj, contains registers i and j.
+    Assign(usize, usize),
+    /// LOOP xi DO ... END.
+    Loop(usize, Vec<Statement>),
+    /// WHILE xi != 0 DO ... END.
+    While(usize, Vec<Statement>),
+}
+
+impl Statement {
+    /// Formats statement with given indentation.
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+        let padding = "  ".repeat(indent);
+        match self {
+            Statement::Zero(register) => writeln!(f, "{padding}x{register} := 0"),
+            Statement::Successor(register) => {
+                writeln!(f, "{padding}x{register} := x{register} + 1")
+            }
+            Statement::Assign(to, from) => writeln!(f, "{padding}x{to} := x{from}"),
+            Statement::Loop(register, body) | Statement::While(register, body) => {
+                match self {
+                    Statement::Loop(..) => writeln!(f, "{padding}LOOP x{register} DO")?,
+                    _ => writeln!(f, "{padding}WHILE x{register} != 0 DO")?,
+                }
+                for statement in body {
+                    statement.fmt_indented(f, indent + 1)?;
+                }
+                writeln!(f, "{padding}END")
+            }
+        }
+    }
+
+    /// Returns the greatest register which is used by statement.
+    fn max_register(&self) -> usize {
+        match self {
+            Statement::Zero(register) | Statement::Successor(register) => *register,
+            Statement::Assign(to, from) => *to.max(from),
+            Statement::Loop(register, body) | Statement::While(register, body) => body
+                .iter()
+                .map(Statement::max_register)
+                .fold(*register, usize::max),
+        }
+    }
+
+    /// Checks if statement contains WHILE statements.
+    fn uses_while(&self) -> bool {
+        match self {
+            Statement::While(..) => true,
+            Statement::Loop(_, body) => body.iter().any(Statement::uses_while),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Statement {
+    /// Formats statement, bodies of LOOP and WHILE statements are indented.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// LOOP or WHILE program.
+pub struct LoopProgram {
+    /// count of arguments which program gets in registers x1, x2, ...
+    pub arguments_count: u32,
+    /// statements of program.
+    pub statements: Vec<Statement>,
+}
+
+impl LoopProgram {
+    /// Returns count of registers which are used by program including result register x0.
+    pub fn registers_count(&self) -> usize {
+        self.statements
+            .iter()
+            .map(Statement::max_register)
+            .fold(self.arguments_count as usize, usize::max)
+            + 1
+    }
+
+    /// Checks if program is LOOP program, which means that it doesn't contain WHILE statements.
+    pub fn is_loop_program(&self) -> bool {
+        !self.statements.iter().any(Statement::uses_while)
+    }
+}
+
+impl fmt::Display for LoopProgram {
+    /// Formats program with one statement on every line and bodies of LOOP and WHILE statements indented.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for statement in self.statements.iter() {
+            statement.fmt_indented(f, 0)?;
+        }
+        Ok(())
+    }
+}
+
+/// Translates recursive function into LOOP program, minimization functions are translated using WHILE statements.
+///
+/// # Arguments
+///
+/// * `function` - recursive function.
+///
+/// # Returns
+///
+/// Program which computes function.
+pub fn translate(function: &RecurFunction) -> LoopProgram {
+    let arguments_count = function.arguments_count() as usize;
+    let arguments: Vec<usize> = (1..=arguments_count).collect();
+    let mut statements = Vec::new();
+    emit(
+        function,
+        &arguments,
+        0,
+        arguments_count + 1,
+        &mut statements,
+    );
+    LoopProgram {
+        arguments_count: function.arguments_count(),
+        statements,
+    }
+}
+
+/// Emits statements which compute function and put its result into output register.
+/// Output register is written only after all arguments are read, so it can be one of argument registers.
+///
+/// # Arguments
+///
+/// * `function` - recursive function.
+/// * `arguments` - registers which contain arguments of function.
+/// * `output` - register for result.
+/// * `free` - first register which can be used for intermediate results, all following registers can be used too.
+/// * `statements` - emitted statements.
+fn emit(
+    function: &RecurFunction,
+    arguments: &[usize],
+    output: usize,
+    free: usize,
+    statements: &mut Vec<Statement>,
+) {
+    let arguments = match function.arguments_count() {
+        0 => &[],
+        _ => arguments,
+    };
+    match function.function_type() {
+        RecurFunctionType::Zero(_) => statements.push(Statement::Zero(output)),
+        RecurFunctionType::Constant(_, number) => emit_constant(*number, output, statements),
+        RecurFunctionType::Successor => {
+            statements.push(Statement::Assign(output, arguments[0]));
+            statements.push(Statement::Successor(output));
+        }
+        RecurFunctionType::Projection(_, argument_number) => {
+            statements.push(Statement::Assign(
+                output,
+                arguments[*argument_number as usize - 1],
+            ));
+        }
+        RecurFunctionType::Composition(base_function, functions) => {
+            let results: Vec<usize> = (free..free + functions.len()).collect();
+            let free = free + functions.len();
+            for (function, result) in functions.iter().zip(results.iter()) {
+                emit(function, arguments, *result, free, statements);
+            }
+            emit(base_function, &results, output, free, statements);
+        }
+        RecurFunctionType::Primitive(base_function, step_function) => {
+            let (counter_max, base_arguments) = arguments
+                .split_last()
+                .expect("primitive function has at least one argument");
+            let (value, counter) = (free, free + 1);
+            let free = free + 2;
+            emit(base_function, base_arguments, value, free, statements);
+            statements.push(Statement::Zero(counter));
+            let mut step_arguments = base_arguments.to_vec();
+            step_arguments.push(counter);
+            step_arguments.push(value);
+            let mut body = Vec::new();
+            emit(step_function, &step_arguments, value, free, &mut body);
+            body.push(Statement::Successor(counter));
+            statements.push(Statement::Loop(*counter_max, body));
+            statements.push(Statement::Assign(output, value));
+        }
+        RecurFunctionType::Minimization(base_function, max) => {
+            let (tries, value, left, exhausted, previous, current) =
+                (free, free + 1, free + 2, free + 3, free + 4, free + 5);
+            let free = free + 6;
+            let mut base_arguments = arguments.to_vec();
+            base_arguments.push(tries);
+            emit_constant(*max, left, statements);
+            statements.push(Statement::Zero(tries));
+            emit(base_function, &base_arguments, value, free, statements);
+            let mut body = vec![
+                Statement::Zero(exhausted),
+                Statement::Successor(exhausted),
+                Statement::Loop(left, vec![Statement::Zero(exhausted)]),
+                Statement::While(exhausted, Vec::new()),
+                Statement::Zero(previous),
+                Statement::Zero(current),
+                Statement::Loop(
+                    left,
+                    vec![
+                        Statement::Assign(previous, current),
+                        Statement::Successor(current),
+                    ],
+                ),
+                Statement::Assign(left, previous),
+                Statement::Successor(tries),
+            ];
+            emit(base_function, &base_arguments, value, free, &mut body);
+            statements.push(Statement::While(value, body));
+            statements.push(Statement::Assign(output, tries));
+        }
+    }
+}
+
+/// Emits statements which put number into output register,
+/// big numbers are built from their binary digits by doubling.
+///
+/// # Arguments
+///
+/// * `number` - number to put.
+/// * `output` - register for number.
+/// * `statements` - emitted statements.
+fn emit_constant(number: u32, output: usize, statements: &mut Vec<Statement>) {
+    statements.push(Statement::Zero(output));
+    if number <= INCREMENTS_MAX {
+        for _ in 0..number {
+            statements.push(Statement::Successor(output));
+        }
+        return;
+    }
+    for bit in (0..u32::BITS - number.leading_zeros()).rev() {
+        statements.push(Statement::Loop(output, vec![Statement::Successor(output)]));
+        if number >> bit & 1 == 1 {
+            statements.push(Statement::Successor(output));
+        }
+    }
+}
+
+/// Runs program on given arguments using limited number of steps.
+///
+/// # Arguments
+///
+/// * `program` - program to run.
+/// * `arguments` - arguments which are put into registers x1, x2, ...
+/// * `fuel` - max number of executed statements and repetitions.
+///
+/// # Returns
+///
+/// Some(u32) with content of register x0 if program stopped, None if steps ran out or number overflowed.
+pub fn run(program: &LoopProgram, arguments: &[u32], fuel: u64) -> Option<u32> {
+    let mut registers = vec![0u32; program.registers_count().max(arguments.len() + 1)];
+    registers[1..=arguments.len()].copy_from_slice(arguments);
+    let mut fuel = fuel;
+    run_statements(&program.statements, &mut registers, &mut fuel)?;
+    Some(registers[0])
+}
+
+/// Runs statements changing registers, returns None if steps ran out or number overflowed.
+fn run_statements(statements: &[Statement], registers: &mut [u32], fuel: &mut u64) -> Option<()> {
+    for statement in statements {
+        *fuel = fuel.checked_sub(1)?;
+        match statement {
+            Statement::Zero(register) => registers[*register] = 0,
+            Statement::Successor(register) => {
+                registers[*register] = registers[*register].checked_add(1)?
+            }
+            Statement::Assign(to, from) => registers[*to] = registers[*from],
+            Statement::Loop(register, body) => {
+                for _ in 0..registers[*register] {
+                    *fuel = fuel.checked_sub(1)?;
+                    run_statements(body, registers, fuel)?;
+                }
+            }
+            Statement::While(register, body) => {
+                while registers[*register] != 0 {
+                    *fuel = fuel.checked_sub(1)?;
+                    run_statements(body, registers, fuel)?;
+                }
+            }
+        }
+    }
+    Some(())
+}
+
+/// Effect of statements on registers: new content of every register as function of all registers
+/// before statements with registers which this content depends on.
+struct Transformer {
+    /// new contents of registers, all functions have registers count as arguments count.
+    functions: Vec<RecurFunction>,
+    /// registers which new contents depend on.
+    dependencies: Vec<BTreeSet<usize>>,
+}
+
+impl Transformer {
+    /// Creates transformer of statements which don't change registers.
+    fn identity(registers_count: usize) -> Result<Transformer, RecurFunctionParseError> {
+        Ok(Transformer {
+            functions: (0..registers_count)
+                .map(|register| projection(registers_count, register))
+                .collect::<Result<_, _>>()?,
+            dependencies: (0..registers_count)
+                .map(|register| BTreeSet::from([register]))
+                .collect(),
+        })
+    }
+
+    /// Checks if statements change content of register.
+    fn changes(&self, register: usize) -> bool {
+        self.functions[register].function_type()
+            != &RecurFunctionType::Projection(self.functions.len() as u32, register as u32 + 1)
+    }
+
+    /// Creates transformer of statements which are executed after statements of this transformer.
+    fn then(self, next: Transformer) -> Result<Transformer, RecurFunctionParseError> {
+        let mut functions = Vec::new();
+        let mut dependencies = Vec::new();
+        for (function, next_dependencies) in next.functions.iter().zip(next.dependencies.iter()) {
+            functions.push(substitute(function, &self.functions)?);
+            dependencies.push(
+                next_dependencies
+                    .iter()
+                    .flat_map(|register| self.dependencies[*register].iter().copied())
+                    .collect(),
+            );
+        }
+        Ok(Transformer {
+            functions,
+            dependencies,
+        })
+    }
+}
+
+/// Translates LOOP program into recursive function which computes the same results.
+///
+/// Registers changed by every LOOP statement are translated into primitive functions in order of their dependencies,
+/// registers which depend on each other in a cycle are translated into one simultaneous primitive recursion
+/// over their contents encoded into one number by Cantor pairing function.
+///
+/// # Arguments
+///
+/// * `program` - LOOP program.
+///
+/// # Returns
+///
+/// The recursive function or LoopTranslationError wraped in Result if program contains WHILE statements.
+pub fn to_recur_function(program: &LoopProgram) -> Result<RecurFunction, LoopTranslationError> {
+    let registers_count = program.registers_count();
+    let arguments_count = program.arguments_count;
+    let transformer = statements_transformer(&program.statements, registers_count)?;
+    let mut registers = Vec::new();
+    for register in 0..registers_count as u32 {
+        registers.push(if (1..=arguments_count).contains(&register) {
+            RecurFunction::from_function_type(RecurFunctionType::Projection(
+                arguments_count,
+                register,
+            ))?
+        } else {
+            RecurFunction::from_function_type(RecurFunctionType::Zero(arguments_count))?
+        });
+    }
+    Ok(RecurFunction::from_function_type(
+        RecurFunctionType::Composition(Box::new(transformer.functions[0].clone()), registers),
+    )?)
+}
+
+/// Computes transformer of statements.
+fn statements_transformer(
+    statements: &[Statement],
+    registers_count: usize,
+) -> Result<Transformer, LoopTranslationError> {
+    let mut transformer = Transformer::identity(registers_count)?;
+    for statement in statements {
+        transformer = transformer.then(statement_transformer(statement, registers_count)?)?;
+    }
+    Ok(transformer)
+}
+
+/// Computes transformer of one statement.
+fn statement_transformer(
+    statement: &Statement,
+    registers_count: usize,
+) -> Result<Transformer, LoopTranslationError> {
+    let mut transformer = Transformer::identity(registers_count)?;
+    match statement {
+        Statement::Zero(register) => {
+            transformer.functions[*register] =
+                RecurFunction::from_function_type(RecurFunctionType::Zero(registers_count as u32))?;
+            transformer.dependencies[*register] = BTreeSet::new();
+        }
+        Statement::Successor(register) => {
+            transformer.functions[*register] =
+                RecurFunction::from_function_type(RecurFunctionType::Composition(
+                    Box::new(RecurFunction::from_function_type(
+                        RecurFunctionType::Successor,
+                    )?),
+                    vec![projection(registers_count, *register)?],
+                ))?;
+        }
+        Statement::Assign(to, from) => {
+            transformer.functions[*to] = projection(registers_count, *from)?;
+            transformer.dependencies[*to] = BTreeSet::from([*from]);
+        }
+        Statement::Loop(counter, body) => {
+            let body = statements_transformer(body, registers_count)?;
+            let Some(order) = loop_order(&body) else {
+                simultaneous_loop(&mut transformer, &body, *counter)?;
+                return Ok(transformer);
+            };
+            let mut primitive_functions: Vec<Option<RecurFunction>> = vec![None; registers_count];
+            for register in order {
+                let dependencies = body.dependencies[register].clone();
+                let mut step_arguments = Vec::new();
+                for (argument, primitive_function) in primitive_functions.iter().enumerate() {
+                    step_arguments.push(if argument == register {
+                        projection(registers_count + 2, registers_count + 1)?
+                    } else if let (true, Some(primitive_function)) =
+                        (dependencies.contains(&argument), primitive_function)
+                    {
+                        RecurFunction::from_function_type(RecurFunctionType::Composition(
+                            Box::new(primitive_function.clone()),
+                            (0..=registers_count)
+                                .map(|argument| projection(registers_count + 2, argument))
+                                .collect::<Result<_, _>>()?,
+                        ))?
+                    } else {
+                        projection(registers_count + 2, argument)?
+                    });
+                }
+                let primitive_function =
+                    RecurFunction::from_function_type(RecurFunctionType::Primitive(
+                        Box::new(projection(registers_count, register)?),
+                        Box::new(RecurFunction::from_function_type(
+                            RecurFunctionType::Composition(
+                                Box::new(body.functions[register].clone()),
+                                step_arguments,
+                            ),
+                        )?),
+                    ))?;
+                let mut register_dependencies = BTreeSet::from([register, *counter]);
+                for argument in dependencies {
+                    if argument != register && body.changes(argument) {
+                        register_dependencies
+                            .extend(transformer.dependencies[argument].iter().copied());
+                    } else {
+                        register_dependencies.insert(argument);
+                    }
+                }
+                let mut arguments = Vec::new();
+                for argument in 0..registers_count {
+                    arguments.push(if register_dependencies.contains(&argument) {
+                        projection(registers_count, argument)?
+                    } else {
+                        RecurFunction::from_function_type(RecurFunctionType::Zero(
+                            registers_count as u32,
+                        ))?
+                    });
+                }
+                arguments.push(projection(registers_count, *counter)?);
+                transformer.functions[register] = RecurFunction::from_function_type(
+                    RecurFunctionType::Composition(Box::new(primitive_function.clone()), arguments),
+                )?;
+                primitive_functions[register] = Some(primitive_function);
+                transformer.dependencies[register] = register_dependencies;
+            }
+        }
+        Statement::While(register, _) => {
+            return Err(LoopTranslationError::UntranslatableProgram(format!(
+                "WHILE x{register} != 0 DO ... END"
+            )))
+        }
+    }
+    Ok(transformer)
+}
+
+/// Orders registers changed by body of LOOP statement so that every register follows registers it depends on,
+/// returns None if registers depend on each other in a cycle.
+fn loop_order(body: &Transformer) -> Option<Vec<usize>> {
+    let mut left: Vec<usize> = (0..body.functions.len())
+        .filter(|register| body.changes(*register))
+        .collect();
+    let mut order = Vec::new();
+    while !left.is_empty() {
+        let position = left.iter().position(|register| {
+            body.dependencies[*register]
+                .iter()
+                .all(|argument| argument == register || !left.contains(argument))
+        })?;
+        order.push(left.remove(position));
+    }
+    Some(order)
+}
+
+/// Translates LOOP statement whose registers depend on each other into one simultaneous primitive recursion.
+///
+/// Contents of changed registers which body reads are encoded into one number,
+/// primitive function computes this number after given count of repetitions.
+/// Contents of changed registers which body only writes are computed from number after one repetition less.
+///
+/// # Arguments
+///
+/// * `transformer` - transformer of LOOP statement whose functions of changed registers are set.
+/// * `body` - transformer of body of LOOP statement.
+/// * `counter` - register which contains count of repetitions.
+fn simultaneous_loop(
+    transformer: &mut Transformer,
+    body: &Transformer,
+    counter: usize,
+) -> Result<(), LoopTranslationError> {
+    let registers_count = body.functions.len();
+    let changed: Vec<usize> = (0..registers_count)
+        .filter(|register| body.changes(*register))
+        .collect();
+    let state: Vec<usize> = changed
+        .iter()
+        .copied()
+        .filter(|register| {
+            changed
+                .iter()
+                .any(|changed| body.dependencies[*changed].contains(register))
+        })
+        .collect();
+    let pairing = Pairing::new()?;
+    let step_registers = pairing.decode_registers(
+        &projection(registers_count + 2, registers_count + 1)?,
+        &state,
+        registers_count,
+    )?;
+    let step_functions: Vec<RecurFunction> = state
+        .iter()
+        .map(|register| composition(&body.functions[*register], step_registers.clone()))
+        .collect::<Result<_, _>>()?;
+    let base_functions: Vec<RecurFunction> = state
+        .iter()
+        .map(|register| projection(registers_count, *register))
+        .collect::<Result<_, _>>()?;
+    let primitive_function = RecurFunction::from_function_type(RecurFunctionType::Primitive(
+        Box::new(pairing.encode(base_functions)?),
+        Box::new(pairing.encode(step_functions)?),
+    ))?;
+    let mut arguments: Vec<RecurFunction> = (0..registers_count)
+        .map(|register| projection(registers_count, register))
+        .collect::<Result<_, _>>()?;
+    arguments.push(projection(registers_count, counter)?);
+    let code = composition(&primitive_function, arguments.clone())?;
+    // registers before the last repetition are computed from arguments of step function without previous value
+    let previous_arguments: Vec<RecurFunction> = (0..=registers_count)
+        .map(|register| projection(registers_count + 2, register))
+        .collect::<Result<_, _>>()?;
+    let previous_code = composition(&primitive_function, previous_arguments)?;
+    let previous_registers = pairing.decode_registers(&previous_code, &state, registers_count)?;
+    let mut dependencies = BTreeSet::from([counter]);
+    for register in changed.iter() {
+        dependencies.insert(*register);
+        dependencies.extend(body.dependencies[*register].iter().copied());
+    }
+    for register in changed {
+        transformer.functions[register] = match state.iter().position(|state| *state == register) {
+            Some(position) => pairing.decode(&code, position, state.len())?,
+            None => {
+                let write_function =
+                    RecurFunction::from_function_type(RecurFunctionType::Primitive(
+                        Box::new(projection(registers_count, register)?),
+                        Box::new(composition(
+                            &body.functions[register],
+                            previous_registers.clone(),
+                        )?),
+                    ))?;
+                composition(&write_function, arguments.clone())?
+            }
+        };
+        transformer.dependencies[register] = dependencies.clone();
+    }
+    Ok(())
+}
+
+/// Cantor pairing function pair(a, b) = (a + b)(a + b + 1) / 2 + b and its inverse functions,
+/// all of them are primitive recursive.
+struct Pairing {
+    /// pairing function of two arguments.
+    pair: RecurFunction,
+    /// first number of pair.
+    first: RecurFunction,
+    /// second number of pair.
+    second: RecurFunction,
+}
+
+impl Pairing {
+    /// Creates pairing functions from their definitions.
+    fn new() -> Result<Pairing, RecurFunctionParseError> {
+        let addition = "[$p1.1, ($s:$p3.3)]";
+        let subtraction = "[$p1.1, ([$z, $p2.1] : $p3.3)]";
+        // triangle(n) = n(n + 1) / 2
+        let triangle = format!("[$z, ({addition} : $p2.2, ($s : $p2.1))]");
+        // diagonal(n) = a + b where n = pair(a, b), it grows when n + 1 is triangle number
+        let diagonal = format!(
+            "[$z, ({addition} : $p2.2, ([1, $z] : ({subtraction} : ({triangle} : ($s : $p2.2)), ($s : $p2.1))))]"
+        );
+        let second = format!("({subtraction} : $p1.1, ({triangle} : {diagonal}))");
+        let first = format!("({subtraction} : {diagonal}, {second})");
+        let pair = format!("({addition} : ({triangle} : {addition}), $p2.2)");
+        let mut identifier_functions = parse_recur_functions(&format!(
+            "pair = {pair}; first = {first}; second = {second};"
+        ))?;
+        let mut take = |identifier: &str| {
+            identifier_functions.remove(identifier).ok_or(
+                RecurFunctionParseError::UndefinedIdentifier(identifier.to_string()),
+            )
+        };
+        Ok(Pairing {
+            pair: take("pair")?,
+            first: take("first")?,
+            second: take("second")?,
+        })
+    }
+
+    /// Encodes results of functions into one number pair(f1, pair(f2, ..., pair(fm-1, fm))).
+    fn encode(
+        &self,
+        mut functions: Vec<RecurFunction>,
+    ) -> Result<RecurFunction, RecurFunctionParseError> {
+        let mut code = functions
+            .pop()
+            .ok_or(RecurFunctionParseError::FunctionExpected(
+                "encoded functions".to_string(),
+            ))?;
+        while let Some(function) = functions.pop() {
+            code = composition(&self.pair, vec![function, code])?;
+        }
+        Ok(code)
+    }
+
+    /// Decodes result of function with given position from code of given count of functions.
+    fn decode(
+        &self,
+        code: &RecurFunction,
+        position: usize,
+        count: usize,
+    ) -> Result<RecurFunction, RecurFunctionParseError> {
+        let mut function = code.clone();
+        for _ in 0..position {
+            function = composition(&self.second, vec![function])?;
+        }
+        if position + 1 < count {
+            function = composition(&self.first, vec![function])?;
+        }
+        Ok(function)
+    }
+
+    /// Creates arguments of step function which are registers at the start of repetition,
+    /// registers of state are decoded from code, other registers are unchanged by repetitions.
+    fn decode_registers(
+        &self,
+        code: &RecurFunction,
+        state: &[usize],
+        registers_count: usize,
+    ) -> Result<Vec<RecurFunction>, RecurFunctionParseError> {
+        (0..registers_count)
+            .map(
+                |register| match state.iter().position(|state| *state == register) {
+                    Some(position) => self.decode(code, position, state.len()),
+                    None => projection(registers_count + 2, register),
+                },
+            )
+            .collect()
+    }
+}
+
+/// Substitutes functions for arguments of function.
+///
+/// # Arguments
+///
+/// * `function` - function whose arguments are substituted.
+/// * `arguments` - functions which replace arguments, all of them have registers count as arguments count.
+///
+/// # Returns
+///
+/// The function of substituted functions arguments or RecurFunctionParseError wraped in Result.
+fn substitute(
+    function: &RecurFunction,
+    arguments: &[RecurFunction],
+) -> Result<RecurFunction, RecurFunctionParseError> {
+    match function.function_type() {
+        _ if function.arguments_count() == 0 => Ok(function.clone()),
+        RecurFunctionType::Zero(_) | RecurFunctionType::Constant(_, _) => Ok(function.clone()),
+        RecurFunctionType::Projection(_, argument_number) => {
+            Ok(arguments[*argument_number as usize - 1].clone())
+        }
+        RecurFunctionType::Composition(base_function, functions) => {
+            RecurFunction::from_function_type(RecurFunctionType::Composition(
+                base_function.clone(),
+                functions
+                    .iter()
+                    .map(|function| substitute(function, arguments))
+                    .collect::<Result<_, _>>()?,
+            ))
+        }
+        _ => RecurFunction::from_function_type(RecurFunctionType::Composition(
+            Box::new(function.clone()),
+            arguments.to_vec(),
+        )),
+    }
+}
+
+/// Creates composition of base function with functions.
+fn composition(
+    base_function: &RecurFunction,
+    functions: Vec<RecurFunction>,
+) -> Result<RecurFunction, RecurFunctionParseError> {
+    RecurFunction::from_function_type(RecurFunctionType::Composition(
+        Box::new(base_function.clone()),
+        functions,
+    ))
+}
+
+/// Creates projection function which selects register from all registers.
+fn projection(
+    registers_count: usize,
+    register: usize,
+) -> Result<RecurFunction, RecurFunctionParseError> {
+    RecurFunction::from_function_type(RecurFunctionType::Projection(
+        registers_count as u32,
+        register as u32 + 1,
+    ))
+}
@@ -1,7 +1,27 @@
-use recur_func_parser::{execute_query, parse_query, parse_recur_functions, RecurFunction};
+use recur_func_parser::classify::{classify_functions, RecurFunctionClass};
+use recur_func_parser::codegen::{generate, generate_wasm, Language};
+use recur_func_parser::equiv::{check_equivalence, Equivalence, DEFAULT_FUEL};
+use recur_func_parser::godel::{encode, enumerate};
+use recur_func_parser::grade::{grade, GradeOptions};
+use recur_func_parser::graph::{
+    export_dependency_graph, export_function_tree, GraphFormat, GraphOptions,
+};
+use recur_func_parser::growth::estimate_growth;
+use recur_func_parser::lambda;
+use recur_func_parser::lint::lint;
+use recur_func_parser::loop_program::translate;
+use recur_func_parser::notation::{render, render_all, Notation};
+use recur_func_parser::synth::{parse_examples, synthesize, Synthesis, SynthesisOptions};
+use recur_func_parser::turing;
+use recur_func_parser::urm;
+use recur_func_parser::{
+    execute_query, parse_query, parse_recur_functions, parse_recur_functions_with_restrictions,
+    RecurFunction, Restrictions,
+};
 use std::collections::HashMap;
 use std::env;
 use std::io::{self, Write};
+use std::time::Duration;
 
 const HELP: &str = r#"
 Usage: recur-func-parser <COMMAND> [OPTIONS]
@@ -11,6 +31,102 @@ Commands:
     Options:
       -p, --print           Print parsing result
       -e, --execute         Start execution loop to execute input queries. To stop it, type: ':exit'
+      -t, --target <IDENTIFIER>
+                            Apply restrictions below to definition with given identifier
+      -a, --allow <IDENTIFIER>
+                            Target definitions can refer only to allowed identifiers
+      --no-primitive        Target definitions must not use primitive functions
+      --no-minimization     Target definitions must not use minimization functions
+
+  check <FILE_PATH>         Check which functions are primitive recursive and which can be undefined,
+                            estimate their level in Grzegorczyk hierarchy and execution steps
+    Options:
+      -r, --primitive <IDENTIFIER>
+                            Fail if function with given identifier is not primitive recursive
+
+  lint <FILE_PATH>          Warn about unused functions, unused arguments and step functions
+                            which ignore recursion counter or previous value
+    Options:
+      -u, --used <IDENTIFIER>
+                            Treat function with given identifier as used by queries
+
+  equiv <FILE_PATH> <IDENTIFIER> <IDENTIFIER>
+                            Check that two functions agree on all arguments up to bound
+    Options:
+      -n, --up-to <BOUND>   Max value of every argument, 10 is default
+      --fuel <STEPS>        Max steps of every execution, 1000000 is default
+
+  grade <REFERENCE_FILE_PATH> <SUBMISSION_FILE_PATH>
+                            Grade submitted functions against reference functions and print report in JSON
+    Options:
+      -n, --up-to <BOUND>   Max value of every argument, 10 is default
+      --fuel <STEPS>        Max steps of every execution, 1000000 is default
+      --no-minimization     Submitted functions must not use minimization
+      -a, --allow <IDENTIFIER>
+                            Submitted functions can refer directly only to allowed identifiers
+
+  index <FILE_PATH> <IDENTIFIER>
+                            Print Gödel number of function
+
+  enumerate <ARGUMENTS_COUNT>
+                            Print first functions with given arguments count in order of their Gödel numbers
+    Options:
+      -c, --count <COUNT>   Count of functions, 10 is default
+
+  synth <FILE_PATH> <EXAMPLES>
+                            Find the smallest function which matches examples, for example: "f 0 = 1; f 3 = 4",
+                            basic functions and functions from file can be used
+    Options:
+      -s, --size <SIZE>     Max count of nodes in syntax tree of function, 8 is default
+      --fuel <STEPS>        Max steps of every execution, 1000000 is default
+      -t, --time <SECONDS>  Max time of search, 10 is default
+      --nodes <COUNT>       Max count of generated nodes of candidate functions
+      --no-minimization     Don't use minimization functions
+
+  lambda <FILE_PATH> <IDENTIFIER>
+                            Print lambda term which computes function on Church numerals
+    Options:
+      -r, --run <ARGUMENTS> Reduce term applied to comma-separated arguments and print result
+                            with count of beta reductions
+      --fuel <STEPS>        Max beta reductions, 1000000 is default
+
+  loop <FILE_PATH> <IDENTIFIER>
+                            Print LOOP program which computes function, minimization is translated
+                            using WHILE statements, arguments are in registers x1, x2, ... and result is in x0
+
+  turing <FILE_PATH> <IDENTIFIER>
+                            Print transition table of Turing machine which computes function
+                            in format of turingmachinesimulator.com, arguments are in unary
+                            encoding separated by '#', for example: #111#11#
+    Options:
+      -r, --run <ARGUMENTS> Run machine on comma-separated arguments and print result with count of steps
+      --fuel <STEPS>        Max steps of run, 1000000 is default
+
+  urm <FILE_PATH> <IDENTIFIER>
+                            Print Unlimited Register Machine program which computes function,
+                            arguments are in registers R1, R2, ... and result is in register R1
+
+  codegen <LANGUAGE> <FILE_PATH>
+                            Print source code with one function for every definition,
+                            language: rust, c, python, wat or wasm (binary WebAssembly module)
+
+  render <FORMAT> <FILE_PATH>
+                            Print definitions in textbook notation, format: latex or mathml
+    Options:
+      -i, --identifier <IDENTIFIER>
+                            Print only definition of given function
+
+  graph <FILE_PATH>         Print dependency graph of functions
+    Options:
+      -f, --format <FORMAT> Graph format: dot or mermaid, dot is default
+      -i, --identifier <IDENTIFIER>
+                            Print only given function and functions it depends on
+      -m, --minimization    Colour functions which use minimization
+
+  tree <FILE_PATH> <IDENTIFIER>
+                            Print syntax tree of function in DOT format
+    Options:
+      -d, --depth <DEPTH>   Expand functions referred by identifier up to given depth, 0 is default
 
   help                      Print this help message
   credits                   Print project credits and information
@@ -46,10 +162,21 @@ fn execution_loop(identifier_functions: &HashMap<String, RecurFunction>) {
         }
         let query_res = parse_query(&input_line, identifier_functions);
         match query_res {
-            Ok(query) => match execute_query(&query, identifier_functions) {
-                Some(number) => println!("Result: {number}"),
-                None => println!("Result: Undefined"),
-            },
+            Ok(query) => {
+                if let Some(function) = identifier_functions.get(query.identifier()) {
+                    let growth_estimate = estimate_growth(function);
+                    if growth_estimate.is_enormous() {
+                        println!(
+                            "Warning: this is level-{}, expect enormous runtime (up to {} steps)",
+                            growth_estimate.grzegorczyk_level, growth_estimate.step_bound
+                        );
+                    }
+                }
+                match execute_query(&query, identifier_functions) {
+                    Some(number) => println!("Result: {number}"),
+                    None => println!("Result: Undefined"),
+                }
+            }
             Err(error) => {
                 eprintln! {"Error: {}", error};
             }
@@ -57,6 +184,73 @@ fn execution_loop(identifier_functions: &HashMap<String, RecurFunction>) {
     }
 }
 
+fn parse_file(file_path: &str) -> Option<HashMap<String, RecurFunction>> {
+    let file_content = std::fs::read_to_string(file_path).expect("could not read file");
+    match parse_recur_functions(&file_content) {
+        Ok(identifier_functions) => Some(identifier_functions),
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            None
+        }
+    }
+}
+
+fn parse_arguments(arguments: &str) -> Option<Vec<u32>> {
+    arguments
+        .split(',')
+        .filter(|argument| !argument.is_empty())
+        .map(|argument| argument.trim().parse::<u32>().ok())
+        .collect()
+}
+
+fn check(identifier_functions: &HashMap<String, RecurFunction>, primitive_identifiers: &[String]) {
+    let classes = classify_functions(identifier_functions);
+    for (identifier, class) in classes.iter() {
+        let growth_estimate = estimate_growth(&identifier_functions[identifier]);
+        let growth = format!(
+            "level {}, {} steps",
+            growth_estimate.grzegorczyk_level, growth_estimate.step_bound
+        );
+        match class {
+            RecurFunctionClass::PrimitiveRecursive => {
+                println!("{identifier}: primitive recursive, {growth}");
+            }
+            RecurFunctionClass::BoundedMinimization(partial_minimizations) => {
+                if partial_minimizations.is_empty() {
+                    println!("{identifier}: bounded minimization, total, {growth}");
+                    continue;
+                }
+                println!(
+                    "{identifier}: bounded minimization, {growth}, can be undefined because of:"
+                );
+                for partial_minimization in partial_minimizations {
+                    println!(
+                        "  {}: {}",
+                        partial_minimization.identifier, partial_minimization.function
+                    );
+                }
+            }
+        }
+    }
+    let mut failed: bool = false;
+    for identifier in primitive_identifiers {
+        match classes.get(identifier) {
+            Some(class) if class.is_primitive_recursive() => {}
+            Some(_) => {
+                eprintln!("Error: {identifier} is not primitive recursive");
+                failed = true;
+            }
+            None => {
+                eprintln!("Error: Undefined identifier: {identifier}");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
 fn main() {
     let args: Vec<String> = env::args().collect();
 
@@ -81,17 +275,12 @@ fn main() {
                 );
                 return;
             }
-            let file_content = std::fs::read_to_string(&args[2]).expect("could not read file");
-            let identifier_functions = match parse_recur_functions(&file_content) {
-                Ok(identifier_functions) => identifier_functions,
-                Err(error) => {
-                    eprintln!("Error: {}", error);
-                    return;
-                }
-            };
             let mut to_print: bool = false;
             let mut to_execute: bool = false;
-            for arg in args.iter().skip(3) {
+            let mut targets: Vec<String> = Vec::new();
+            let mut restrictions = Restrictions::default();
+            let mut option_args = args.iter().skip(3);
+            while let Some(arg) = option_args.next() {
                 match arg.as_str() {
                     "-p" | "--print" => {
                         to_print = true;
@@ -99,12 +288,54 @@ fn main() {
                     "-e" | "--execute" => {
                         to_execute = true;
                     }
+                    "-t" | "--target" => match option_args.next() {
+                        Some(identifier) => targets.push(identifier.clone()),
+                        None => {
+                            eprintln!("No identifier provided for option: {}", arg);
+                            return;
+                        }
+                    },
+                    "-a" | "--allow" => match option_args.next() {
+                        Some(identifier) => restrictions
+                            .allowed_identifiers
+                            .get_or_insert_with(Vec::new)
+                            .push(identifier.clone()),
+                        None => {
+                            eprintln!("No identifier provided for option: {}", arg);
+                            return;
+                        }
+                    },
+                    "--no-primitive" => {
+                        restrictions.forbid_primitive = true;
+                    }
+                    "--no-minimization" => {
+                        restrictions.forbid_minimization = true;
+                    }
                     _ => {
                         eprintln!("Unknown option: {}. Use 'help' for usage information", arg);
                         return;
                     }
                 }
             }
+            if targets.is_empty() && restrictions != Restrictions::default() {
+                eprintln!("No target provided for restrictions. Use 'help' for usage information");
+                return;
+            }
+            let identifier_restrictions: HashMap<String, Restrictions> = targets
+                .into_iter()
+                .map(|identifier| (identifier, restrictions.clone()))
+                .collect();
+            let file_content = std::fs::read_to_string(&args[2]).expect("could not read file");
+            let identifier_functions = match parse_recur_functions_with_restrictions(
+                &file_content,
+                &identifier_restrictions,
+            ) {
+                Ok(identifier_functions) => identifier_functions,
+                Err(error) => {
+                    eprintln!("Error: {}", error);
+                    return;
+                }
+            };
             if to_print {
                 println!("{:?}", identifier_functions);
             }
@@ -112,6 +343,634 @@ fn main() {
                 execution_loop(&identifier_functions);
             }
         }
+        "check" => {
+            if args.len() < 3 {
+                eprintln!(
+                    "No input file provided for check command. Use 'help' for usage information"
+                );
+                return;
+            }
+            let Some(identifier_functions) = parse_file(&args[2]) else {
+                return;
+            };
+            let mut primitive_identifiers: Vec<String> = Vec::new();
+            let mut options = args.iter().skip(3);
+            while let Some(arg) = options.next() {
+                match arg.as_str() {
+                    "-r" | "--primitive" => match options.next() {
+                        Some(identifier) => primitive_identifiers.push(identifier.clone()),
+                        None => {
+                            eprintln!("No identifier provided for option: {}", arg);
+                            return;
+                        }
+                    },
+                    _ => {
+                        eprintln!("Unknown option: {}. Use 'help' for usage information", arg);
+                        return;
+                    }
+                }
+            }
+            check(&identifier_functions, &primitive_identifiers);
+        }
+        "lint" => {
+            if args.len() < 3 {
+                eprintln!(
+                    "No input file provided for lint command. Use 'help' for usage information"
+                );
+                return;
+            }
+            let file_content = std::fs::read_to_string(&args[2]).expect("could not read file");
+            let mut used_identifiers: Vec<String> = Vec::new();
+            let mut option_args = args.iter().skip(3);
+            while let Some(arg) = option_args.next() {
+                match arg.as_str() {
+                    "-u" | "--used" => match option_args.next() {
+                        Some(identifier) => used_identifiers.push(identifier.clone()),
+                        None => {
+                            eprintln!("No identifier provided for option: {}", arg);
+                            return;
+                        }
+                    },
+                    _ => {
+                        eprintln!("Unknown option: {}. Use 'help' for usage information", arg);
+                        return;
+                    }
+                }
+            }
+            match lint(&file_content, &used_identifiers) {
+                Ok(lints) => {
+                    for lint in lints {
+                        println!("{}:{lint}", args[2]);
+                    }
+                }
+                Err(error) => eprintln!("Error: {}", error),
+            }
+        }
+        "equiv" => {
+            if args.len() < 5 {
+                eprintln!(
+                    "No input file or identifiers provided for equiv command. Use 'help' for usage information"
+                );
+                return;
+            }
+            let Some(identifier_functions) = parse_file(&args[2]) else {
+                return;
+            };
+            let mut functions: Vec<&RecurFunction> = Vec::new();
+            for identifier in &args[3..5] {
+                match identifier_functions.get(identifier) {
+                    Some(function) => functions.push(function),
+                    None => {
+                        eprintln!("Error: Undefined identifier: {identifier}");
+                        return;
+                    }
+                }
+            }
+            let mut up_to: u32 = 10;
+            let mut fuel: u64 = DEFAULT_FUEL;
+            let mut option_args = args.iter().skip(5);
+            while let Some(arg) = option_args.next() {
+                match arg.as_str() {
+                    "-n" | "--up-to" => {
+                        match option_args.next().and_then(|arg| arg.parse::<u32>().ok()) {
+                            Some(bound) => up_to = bound,
+                            None => {
+                                eprintln!("Expected bound for option: {}", arg);
+                                return;
+                            }
+                        }
+                    }
+                    "--fuel" => match option_args.next().and_then(|arg| arg.parse::<u64>().ok()) {
+                        Some(steps) => fuel = steps,
+                        None => {
+                            eprintln!("Expected steps for option: {}", arg);
+                            return;
+                        }
+                    },
+                    _ => {
+                        eprintln!("Unknown option: {}. Use 'help' for usage information", arg);
+                        return;
+                    }
+                }
+            }
+            match check_equivalence(functions[0], functions[1], up_to, fuel) {
+                Ok(Equivalence::Equivalent { checked, exhausted }) => {
+                    println!(
+                        "{} and {} agree on {} of {checked} argument tuples up to {up_to}",
+                        args[3],
+                        args[4],
+                        checked - exhausted
+                    );
+                    if exhausted > 0 {
+                        println!("Warning: {exhausted} argument tuples ran out of fuel");
+                    }
+                }
+                Ok(Equivalence::Counterexample(counterexample)) => {
+                    let arguments: Vec<String> = counterexample
+                        .arguments
+                        .iter()
+                        .map(|argument| argument.to_string())
+                        .collect();
+                    let arguments = arguments.join(", ");
+                    println!("Counterexample:");
+                    println!("  {}({arguments}) = {}", args[3], counterexample.first);
+                    println!("  {}({arguments}) = {}", args[4], counterexample.second);
+                    std::process::exit(1);
+                }
+                Err(error) => eprintln!("Error: {}", error),
+            }
+        }
+        "grade" => {
+            if args.len() < 4 {
+                eprintln!(
+                    "No reference or submission file provided for grade command. Use 'help' for usage information"
+                );
+                return;
+            }
+            let Some(reference) = parse_file(&args[2]) else {
+                return;
+            };
+            let Some(submission) = parse_file(&args[3]) else {
+                return;
+            };
+            let mut options = GradeOptions::default();
+            let mut option_args = args.iter().skip(4);
+            while let Some(arg) = option_args.next() {
+                match arg.as_str() {
+                    "-n" | "--up-to" => {
+                        match option_args.next().and_then(|arg| arg.parse::<u32>().ok()) {
+                            Some(bound) => options.up_to = bound,
+                            None => {
+                                eprintln!("Expected bound for option: {}", arg);
+                                return;
+                            }
+                        }
+                    }
+                    "--fuel" => match option_args.next().and_then(|arg| arg.parse::<u64>().ok()) {
+                        Some(steps) => options.fuel = steps,
+                        None => {
+                            eprintln!("Expected steps for option: {}", arg);
+                            return;
+                        }
+                    },
+                    "--no-minimization" => {
+                        options.forbid_minimization = true;
+                    }
+                    "-a" | "--allow" => match option_args.next() {
+                        Some(identifier) => options
+                            .allowed_identifiers
+                            .get_or_insert_with(Vec::new)
+                            .push(identifier.clone()),
+                        None => {
+                            eprintln!("No identifier provided for option: {}", arg);
+                            return;
+                        }
+                    },
+                    _ => {
+                        eprintln!("Unknown option: {}. Use 'help' for usage information", arg);
+                        return;
+                    }
+                }
+            }
+            print!("{}", grade(&reference, &submission, &options).to_json());
+        }
+        "index" => {
+            if args.len() < 4 {
+                eprintln!(
+                    "No input file or identifier provided for index command. Use 'help' for usage information"
+                );
+                return;
+            }
+            let Some(identifier_functions) = parse_file(&args[2]) else {
+                return;
+            };
+            match identifier_functions.get(&args[3]) {
+                Some(function) => println!("{}", encode(function)),
+                None => eprintln!("Error: Undefined identifier: {}", args[3]),
+            }
+        }
+        "enumerate" => {
+            let Some(arguments_count) = args.get(2).and_then(|arg| arg.parse::<u32>().ok()) else {
+                eprintln!(
+                    "No arguments count provided for enumerate command. Use 'help' for usage information"
+                );
+                return;
+            };
+            let mut count: usize = 10;
+            let mut option_args = args.iter().skip(3);
+            while let Some(arg) = option_args.next() {
+                match arg.as_str() {
+                    "-c" | "--count" => {
+                        match option_args.next().and_then(|arg| arg.parse::<usize>().ok()) {
+                            Some(number) => count = number,
+                            None => {
+                                eprintln!("Expected count for option: {}", arg);
+                                return;
+                            }
+                        }
+                    }
+                    _ => {
+                        eprintln!("Unknown option: {}. Use 'help' for usage information", arg);
+                        return;
+                    }
+                }
+            }
+            for (number, function) in enumerate(arguments_count, count) {
+                println!("{number}: {function}");
+            }
+        }
+        "synth" => {
+            if args.len() < 4 {
+                eprintln!(
+                    "No input file or examples provided for synth command. Use 'help' for usage information"
+                );
+                return;
+            }
+            let Some(identifier_functions) = parse_file(&args[2]) else {
+                return;
+            };
+            let (identifier, examples) = match parse_examples(&args[3]) {
+                Ok(examples) => examples,
+                Err(error) => {
+                    eprintln!("Error: {}", error);
+                    return;
+                }
+            };
+            let mut options = SynthesisOptions::default();
+            let mut option_args = args.iter().skip(4);
+            while let Some(arg) = option_args.next() {
+                match arg.as_str() {
+                    "-s" | "--size" => {
+                        match option_args.next().and_then(|arg| arg.parse::<u32>().ok()) {
+                            Some(size) => options.max_size = size,
+                            None => {
+                                eprintln!("Expected size for option: {}", arg);
+                                return;
+                            }
+                        }
+                    }
+                    "--fuel" => match option_args.next().and_then(|arg| arg.parse::<u64>().ok()) {
+                        Some(steps) => options.fuel = steps,
+                        None => {
+                            eprintln!("Expected steps for option: {}", arg);
+                            return;
+                        }
+                    },
+                    "-t" | "--time" => {
+                        match option_args.next().and_then(|arg| arg.parse::<u64>().ok()) {
+                            Some(seconds) => options.time_limit = Duration::from_secs(seconds),
+                            None => {
+                                eprintln!("Expected seconds for option: {}", arg);
+                                return;
+                            }
+                        }
+                    }
+                    "--nodes" => match option_args.next().and_then(|arg| arg.parse::<u64>().ok()) {
+                        Some(count) => options.max_nodes = Some(count),
+                        None => {
+                            eprintln!("Expected count for option: {}", arg);
+                            return;
+                        }
+                    },
+                    "--no-minimization" => {
+                        options.enumeration.minimization_max = None;
+                    }
+                    _ => {
+                        eprintln!("Unknown option: {}. Use 'help' for usage information", arg);
+                        return;
+                    }
+                }
+            }
+            let mut identifier_functions = identifier_functions;
+            identifier_functions.remove(&identifier);
+            match synthesize(&examples, &identifier_functions, &options) {
+                Synthesis::Found(function) => println!("{identifier} = {function};"),
+                Synthesis::NotFound => {
+                    eprintln!(
+                        "No function up to size {} matches examples",
+                        options.max_size
+                    );
+                    std::process::exit(1);
+                }
+                Synthesis::OutOfTime(size) => {
+                    eprintln!("Time ran out while searching functions of size {size}");
+                    std::process::exit(1);
+                }
+                Synthesis::OutOfNodes(size) => {
+                    eprintln!(
+                        "Max count of nodes was reached while searching functions of size {size}"
+                    );
+                    std::process::exit(1);
+                }
+            }
+        }
+        "lambda" => {
+            if args.len() < 4 {
+                eprintln!(
+                    "No input file or identifier provided for lambda command. Use 'help' for usage information"
+                );
+                return;
+            }
+            let Some(identifier_functions) = parse_file(&args[2]) else {
+                return;
+            };
+            let Some(function) = identifier_functions.get(&args[3]) else {
+                eprintln!("Error: Undefined identifier: {}", args[3]);
+                return;
+            };
+            let mut arguments: Option<Vec<u32>> = None;
+            let mut fuel: u64 = DEFAULT_FUEL;
+            let mut option_args = args.iter().skip(4);
+            while let Some(arg) = option_args.next() {
+                match arg.as_str() {
+                    "-r" | "--run" => {
+                        match option_args.next().and_then(|arg| parse_arguments(arg)) {
+                            Some(numbers) => arguments = Some(numbers),
+                            None => {
+                                eprintln!("Expected arguments for option: {}", arg);
+                                return;
+                            }
+                        }
+                    }
+                    "--fuel" => match option_args.next().and_then(|arg| arg.parse::<u64>().ok()) {
+                        Some(steps) => fuel = steps,
+                        None => {
+                            eprintln!("Expected steps for option: {}", arg);
+                            return;
+                        }
+                    },
+                    _ => {
+                        eprintln!("Unknown option: {}. Use 'help' for usage information", arg);
+                        return;
+                    }
+                }
+            }
+            let term = lambda::translate(function);
+            let Some(arguments) = arguments else {
+                println!("{term}");
+                return;
+            };
+            if arguments.len() != function.arguments_count() as usize {
+                eprintln!(
+                    "Error: Invalid arguments count: {} expects {} arguments",
+                    args[3],
+                    function.arguments_count()
+                );
+                return;
+            }
+            match lambda::run(&term, &arguments, fuel) {
+                Some((result, steps)) => println!("{result} ({steps} reductions)"),
+                None => {
+                    eprintln!("Term didn't reduce to Church numeral in {fuel} reductions");
+                    std::process::exit(1);
+                }
+            }
+        }
+        "loop" => {
+            if args.len() < 4 {
+                eprintln!(
+                    "No input file or identifier provided for loop command. Use 'help' for usage information"
+                );
+                return;
+            }
+            let Some(identifier_functions) = parse_file(&args[2]) else {
+                return;
+            };
+            match identifier_functions.get(&args[3]) {
+                Some(function) => print!("{}", translate(function)),
+                None => eprintln!("Error: Undefined identifier: {}", args[3]),
+            }
+        }
+        "turing" => {
+            if args.len() < 4 {
+                eprintln!(
+                    "No input file or identifier provided for turing command. Use 'help' for usage information"
+                );
+                return;
+            }
+            let Some(identifier_functions) = parse_file(&args[2]) else {
+                return;
+            };
+            let Some(function) = identifier_functions.get(&args[3]) else {
+                eprintln!("Error: Undefined identifier: {}", args[3]);
+                return;
+            };
+            let mut arguments: Option<Vec<u32>> = None;
+            let mut fuel: u64 = DEFAULT_FUEL;
+            let mut option_args = args.iter().skip(4);
+            while let Some(arg) = option_args.next() {
+                match arg.as_str() {
+                    "-r" | "--run" => {
+                        match option_args.next().and_then(|arg| parse_arguments(arg)) {
+                            Some(numbers) => arguments = Some(numbers),
+                            None => {
+                                eprintln!("Expected arguments for option: {}", arg);
+                                return;
+                            }
+                        }
+                    }
+                    "--fuel" => match option_args.next().and_then(|arg| arg.parse::<u64>().ok()) {
+                        Some(steps) => fuel = steps,
+                        None => {
+                            eprintln!("Expected steps for option: {}", arg);
+                            return;
+                        }
+                    },
+                    _ => {
+                        eprintln!("Unknown option: {}. Use 'help' for usage information", arg);
+                        return;
+                    }
+                }
+            }
+            let machine = turing::compile(function);
+            let Some(arguments) = arguments else {
+                print!("{}", machine.export(&args[3]));
+                return;
+            };
+            if arguments.len() != function.arguments_count() as usize {
+                eprintln!(
+                    "Error: Invalid arguments count: {} expects {} arguments",
+                    args[3],
+                    function.arguments_count()
+                );
+                return;
+            }
+            match turing::run(&machine, &arguments, fuel) {
+                Some((result, steps)) => println!("{result} ({steps} steps)"),
+                None => {
+                    eprintln!("Machine didn't stop in {fuel} steps");
+                    std::process::exit(1);
+                }
+            }
+        }
+        "urm" => {
+            if args.len() < 4 {
+                eprintln!(
+                    "No input file or identifier provided for urm command. Use 'help' for usage information"
+                );
+                return;
+            }
+            let Some(identifier_functions) = parse_file(&args[2]) else {
+                return;
+            };
+            match identifier_functions.get(&args[3]) {
+                Some(function) => print!("{}", urm::compile(function)),
+                None => eprintln!("Error: Undefined identifier: {}", args[3]),
+            }
+        }
+        "codegen" => {
+            if args.len() < 4 {
+                eprintln!(
+                    "No language or input file provided for codegen command. Use 'help' for usage information"
+                );
+                return;
+            }
+            let language = match args[2].as_str() {
+                "rust" => Language::Rust,
+                "c" => Language::C,
+                "python" => Language::Python,
+                "wat" | "wasm" => Language::Wat,
+                _ => {
+                    eprintln!(
+                        "Expected language rust, c, python, wat or wasm, but got: {}",
+                        args[2]
+                    );
+                    return;
+                }
+            };
+            let Some(identifier_functions) = parse_file(&args[3]) else {
+                return;
+            };
+            if args[2] == "wasm" {
+                if let Err(error) = io::stdout().write_all(&generate_wasm(&identifier_functions)) {
+                    eprintln!("Error: {}", error);
+                }
+                return;
+            }
+            print!("{}", generate(&identifier_functions, language));
+        }
+        "render" => {
+            if args.len() < 4 {
+                eprintln!(
+                    "No format or input file provided for render command. Use 'help' for usage information"
+                );
+                return;
+            }
+            let notation = match args[2].as_str() {
+                "latex" => Notation::Latex,
+                "mathml" => Notation::MathMl,
+                _ => {
+                    eprintln!("Expected format latex or mathml, but got: {}", args[2]);
+                    return;
+                }
+            };
+            let Some(identifier_functions) = parse_file(&args[3]) else {
+                return;
+            };
+            let mut identifier = None;
+            let mut option_args = args.iter().skip(4);
+            while let Some(arg) = option_args.next() {
+                match arg.as_str() {
+                    "-i" | "--identifier" => match option_args.next() {
+                        Some(arg_identifier) => identifier = Some(arg_identifier),
+                        None => {
+                            eprintln!("No identifier provided for option: {}", arg);
+                            return;
+                        }
+                    },
+                    _ => {
+                        eprintln!("Unknown option: {}. Use 'help' for usage information", arg);
+                        return;
+                    }
+                }
+            }
+            match identifier {
+                Some(identifier) => match identifier_functions.get(identifier) {
+                    Some(function) => print!("{}", render(identifier, function, notation)),
+                    None => eprintln!("Error: Undefined identifier: {}", identifier),
+                },
+                None => print!("{}", render_all(&identifier_functions, notation)),
+            }
+        }
+        "graph" => {
+            if args.len() < 3 {
+                eprintln!(
+                    "No input file provided for graph command. Use 'help' for usage information"
+                );
+                return;
+            }
+            let Some(identifier_functions) = parse_file(&args[2]) else {
+                return;
+            };
+            let mut format = GraphFormat::Dot;
+            let mut options = GraphOptions::default();
+            let mut option_args = args.iter().skip(3);
+            while let Some(arg) = option_args.next() {
+                match arg.as_str() {
+                    "-f" | "--format" => match option_args.next().map(|arg| arg.as_str()) {
+                        Some("dot") => format = GraphFormat::Dot,
+                        Some("mermaid") => format = GraphFormat::Mermaid,
+                        _ => {
+                            eprintln!("Expected graph format dot or mermaid for option: {}", arg);
+                            return;
+                        }
+                    },
+                    "-i" | "--identifier" => match option_args.next() {
+                        Some(identifier) => options.identifier = Some(identifier.clone()),
+                        None => {
+                            eprintln!("No identifier provided for option: {}", arg);
+                            return;
+                        }
+                    },
+                    "-m" | "--minimization" => {
+                        options.color_minimization = true;
+                    }
+                    _ => {
+                        eprintln!("Unknown option: {}. Use 'help' for usage information", arg);
+                        return;
+                    }
+                }
+            }
+            match export_dependency_graph(&identifier_functions, format, &options) {
+                Ok(graph) => print!("{graph}"),
+                Err(error) => eprintln!("Error: {}", error),
+            }
+        }
+        "tree" => {
+            if args.len() < 4 {
+                eprintln!(
+                    "No input file or identifier provided for tree command. Use 'help' for usage information"
+                );
+                return;
+            }
+            let Some(identifier_functions) = parse_file(&args[2]) else {
+                return;
+            };
+            let Some(function) = identifier_functions.get(&args[3]) else {
+                eprintln!("Error: Undefined identifier: {}", args[3]);
+                return;
+            };
+            let mut expand_depth: u32 = 0;
+            let mut option_args = args.iter().skip(4);
+            while let Some(arg) = option_args.next() {
+                match arg.as_str() {
+                    "-d" | "--depth" => {
+                        match option_args.next().and_then(|arg| arg.parse::<u32>().ok()) {
+                            Some(depth) => expand_depth = depth,
+                            None => {
+                                eprintln!("Expected depth for option: {}", arg);
+                                return;
+                            }
+                        }
+                    }
+                    _ => {
+                        eprintln!("Unknown option: {}. Use 'help' for usage information", arg);
+                        return;
+                    }
+                }
+            }
+            print!("{}", export_function_tree(&args[3], function, expand_depth));
+        }
         _ => {
             eprintln!("Unknown command: {command}. Use 'help' for usage information");
         }
@@ -0,0 +1,362 @@
+//! Rendering of recursive function definitions in textbook notation as LaTeX or MathML.
+//!
+//! Definition h of primitive function becomes two equations h(x̄, 0) = f(x̄) and h(x̄, y + 1) = g(x̄, y, h(x̄, y)),
+//! other definitions become one equation h(x̄) = ..., where compositions are applications f(g₁(x̄), ...),
+//! minimizations are bounded searches μz ≤ max [f(x̄, z) = 0], projections are I^n_i and successor is s.
+//! Primitive functions inside other functions get names h₁, h₂, ... and their own equations.
+
+use crate::codegen::definitions_order;
+use crate::{RecurFunction, RecurFunctionType};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Formats of rendered definitions.
+pub enum Notation {
+    /// LaTeX `aligned` environment for math mode.
+    Latex,
+    /// MathML `math` element with table of equations.
+    MathMl,
+}
+
+/// Renders definition in textbook notation.
+///
+/// # Arguments
+///
+/// * `identifier` - identifier of function.
+/// * `function` - function of identifier.
+/// * `notation` - format of rendered definition.
+///
+/// # Returns
+///
+/// Equations of definition with source of definition in comment.
+pub fn render(identifier: &str, function: &RecurFunction, notation: Notation) -> String {
+    let syntax: &dyn Syntax = match notation {
+        Notation::Latex => &Latex,
+        Notation::MathMl => &MathMl,
+    };
+    Renderer::new(syntax, identifier).definition(function)
+}
+
+/// Renders all definitions in textbook notation, every definition follows definitions it refers to.
+///
+/// # Arguments
+///
+/// * `identifier_functions` - parsed identifiers and their function.
+/// * `notation` - format of rendered definitions.
+///
+/// # Returns
+///
+/// Rendered definitions separated by empty line.
+pub fn render_all(
+    identifier_functions: &HashMap<String, RecurFunction>,
+    notation: Notation,
+) -> String {
+    definitions_order(identifier_functions)
+        .iter()
+        .map(|identifier| render(identifier, &identifier_functions[identifier], notation))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Syntax of format for parts of equations.
+trait Syntax {
+    /// Name of function with index of inner primitive function.
+    fn name(&self, identifier: &str, index: Option<usize>) -> String;
+
+    /// Variable with optional index.
+    fn variable(&self, name: char, index: Option<usize>) -> String;
+
+    /// Vector of variables x̄.
+    fn vector(&self) -> String;
+
+    /// Number.
+    fn number(&self, number: u32) -> String;
+
+    /// Symbol of successor function.
+    fn successor(&self) -> String;
+
+    /// Symbol of projection function I^n_i.
+    fn projection(&self, arguments_count: u32, argument_number: u32) -> String;
+
+    /// Application of function to arguments, function without arguments is only its symbol.
+    fn application(&self, function: &str, arguments: &[String]) -> String;
+
+    /// Variable increased by one.
+    fn next(&self, variable: &str) -> String;
+
+    /// Bounded search of the least value of variable for which condition is zero.
+    fn minimization(&self, variable: &str, max: u32, condition: &str) -> String;
+
+    /// Equations with left and right sides and source of definition in comment.
+    fn definition(&self, source: &str, equations: &[(String, String)]) -> String;
+}
+
+/// LaTeX syntax, names of functions are upright.
+struct Latex;
+
+impl Syntax for Latex {
+    fn name(&self, identifier: &str, index: Option<usize>) -> String {
+        match index {
+            Some(index) => format!("\\mathrm{{{identifier}}}_{{{index}}}"),
+            None => format!("\\mathrm{{{identifier}}}"),
+        }
+    }
+
+    fn variable(&self, name: char, index: Option<usize>) -> String {
+        match index {
+            Some(index) => format!("{name}_{{{index}}}"),
+            None => name.to_string(),
+        }
+    }
+
+    fn vector(&self) -> String {
+        "\\bar{x}".to_string()
+    }
+
+    fn number(&self, number: u32) -> String {
+        number.to_string()
+    }
+
+    fn successor(&self) -> String {
+        "s".to_string()
+    }
+
+    fn projection(&self, arguments_count: u32, argument_number: u32) -> String {
+        format!("I^{{{arguments_count}}}_{{{argument_number}}}")
+    }
+
+    fn application(&self, function: &str, arguments: &[String]) -> String {
+        match arguments.is_empty() {
+            true => function.to_string(),
+            false => format!("{function}({})", arguments.join(", ")),
+        }
+    }
+
+    fn next(&self, variable: &str) -> String {
+        format!("{variable} + 1")
+    }
+
+    fn minimization(&self, variable: &str, max: u32, condition: &str) -> String {
+        format!("\\mu {variable} \\leq {max}\\, [{condition} = 0]")
+    }
+
+    fn definition(&self, source: &str, equations: &[(String, String)]) -> String {
+        let lines: Vec<String> = equations
+            .iter()
+            .map(|(left, right)| format!("{left} &= {right}"))
+            .collect();
+        format!(
+            "% {source}\n\\begin{{aligned}}\n{}\n\\end{{aligned}}\n",
+            lines.join(" \\\\\n")
+        )
+    }
+}
+
+/// MathML syntax, every part is element of presentation markup.
+struct MathMl;
+
+impl Syntax for MathMl {
+    fn name(&self, identifier: &str, index: Option<usize>) -> String {
+        match index {
+            Some(index) => format!("<msub><mi>{identifier}</mi><mn>{index}</mn></msub>"),
+            None => format!("<mi>{identifier}</mi>"),
+        }
+    }
+
+    fn variable(&self, name: char, index: Option<usize>) -> String {
+        match index {
+            Some(index) => format!("<msub><mi>{name}</mi><mn>{index}</mn></msub>"),
+            None => format!("<mi>{name}</mi>"),
+        }
+    }
+
+    fn vector(&self) -> String {
+        "<mover accent=\"true\"><mi>x</mi><mo>&#x00AF;</mo></mover>".to_string()
+    }
+
+    fn number(&self, number: u32) -> String {
+        format!("<mn>{number}</mn>")
+    }
+
+    fn successor(&self) -> String {
+        "<mi>s</mi>".to_string()
+    }
+
+    fn projection(&self, arguments_count: u32, argument_number: u32) -> String {
+        format!("<msubsup><mi>I</mi><mn>{argument_number}</mn><mn>{arguments_count}</mn></msubsup>")
+    }
+
+    fn application(&self, function: &str, arguments: &[String]) -> String {
+        match arguments.is_empty() {
+            true => function.to_string(),
+            false => format!(
+                "<mrow>{function}<mo>(</mo>{}<mo>)</mo></mrow>",
+                arguments.join("<mo>,</mo>")
+            ),
+        }
+    }
+
+    fn next(&self, variable: &str) -> String {
+        format!("<mrow>{variable}<mo>+</mo><mn>1</mn></mrow>")
+    }
+
+    fn minimization(&self, variable: &str, max: u32, condition: &str) -> String {
+        format!(
+            "<mrow><mi>&#x03BC;</mi>{variable}<mo>&#x2264;</mo><mn>{max}</mn>\
+             <mo>[</mo>{condition}<mo>=</mo><mn>0</mn><mo>]</mo></mrow>"
+        )
+    }
+
+    fn definition(&self, source: &str, equations: &[(String, String)]) -> String {
+        let rows: Vec<String> = equations
+            .iter()
+            .map(|(left, right)| {
+                format!(
+                    "    <mtr><mtd columnalign=\"right\">{left}</mtd>\
+                     <mtd columnalign=\"left\"><mo>=</mo>{right}</mtd></mtr>\n"
+                )
+            })
+            .collect();
+        format!(
+            "<!-- {source} -->\n<math display=\"block\">\n  <mtable>\n{}  </mtable>\n</math>\n",
+            rows.concat()
+        )
+    }
+}
+
+/// Renderer of equations of one definition.
+struct Renderer<'a> {
+    /// syntax of format.
+    syntax: &'a dyn Syntax,
+    /// identifier of rendered definition.
+    identifier: &'a str,
+    /// rendered equations.
+    equations: Vec<(String, String)>,
+    /// inner primitive functions which get equations after equations of definition.
+    primitives: Vec<&'a RecurFunction>,
+    /// count of variables of minimizations.
+    minimizations: usize,
+}
+
+impl<'a> Renderer<'a> {
+    /// Creates renderer of definition with given identifier.
+    fn new(syntax: &'a dyn Syntax, identifier: &'a str) -> Self {
+        Renderer {
+            syntax,
+            identifier,
+            equations: Vec::new(),
+            primitives: Vec::new(),
+            minimizations: 0,
+        }
+    }
+
+    /// Renders equations of definition and its inner primitive functions.
+    fn definition(mut self, function: &'a RecurFunction) -> String {
+        let name = self.syntax.name(self.identifier, None);
+        match (function.identifier(), function.function_type()) {
+            (None, RecurFunctionType::Primitive(base_function, step_function)) => {
+                self.primitive(&name, function, base_function, step_function)
+            }
+            _ => {
+                let arguments = self.vector(function.arguments_count() as usize);
+                let left = self.syntax.application(&name, &arguments);
+                let right = self.apply(function, &arguments);
+                self.equations.push((left, right));
+            }
+        }
+        let mut index = 0;
+        while let Some(function) = self.primitives.get(index).copied() {
+            index += 1;
+            let name = self.syntax.name(self.identifier, Some(index));
+            if let RecurFunctionType::Primitive(base_function, step_function) =
+                function.function_type()
+            {
+                self.primitive(&name, function, base_function, step_function);
+            }
+        }
+        self.syntax.definition(
+            &format!("{} = {function};", self.identifier),
+            &self.equations,
+        )
+    }
+
+    /// Adds equations h(x̄, 0) = f(x̄) and h(x̄, y + 1) = g(x̄, y, h(x̄, y)) of primitive function.
+    fn primitive(
+        &mut self,
+        name: &str,
+        function: &'a RecurFunction,
+        base_function: &'a RecurFunction,
+        step_function: &'a RecurFunction,
+    ) {
+        let arguments = self.vector(function.arguments_count() as usize - 1);
+        let counter = self.syntax.variable('y', None);
+        let with = |last: String| [arguments.clone(), vec![last]].concat();
+        let base_left = self.syntax.application(name, &with(self.syntax.number(0)));
+        let base_right = self.apply(base_function, &arguments);
+        self.equations.push((base_left, base_right));
+        let previous = self.syntax.application(name, &with(counter.clone()));
+        let step_left = self
+            .syntax
+            .application(name, &with(self.syntax.next(&counter)));
+        let step_arguments = [with(counter), vec![previous]].concat();
+        let step_right = self.apply(step_function, &step_arguments);
+        self.equations.push((step_left, step_right));
+    }
+
+    /// Arguments of definition with given count, which are x for one argument and x̄ for more arguments.
+    fn vector(&self, arguments_count: usize) -> Vec<String> {
+        match arguments_count {
+            0 => Vec::new(),
+            1 => vec![self.syntax.variable('x', None)],
+            _ => vec![self.syntax.vector()],
+        }
+    }
+
+    /// Renders application of function to arguments.
+    fn apply(&mut self, function: &'a RecurFunction, arguments: &[String]) -> String {
+        let arguments = match function.arguments_count() {
+            0 => &[],
+            _ => arguments,
+        };
+        if let Some(identifier) = function.identifier() {
+            return self
+                .syntax
+                .application(&self.syntax.name(identifier, None), arguments);
+        }
+        match function.function_type() {
+            RecurFunctionType::Zero(_) => self.syntax.number(0),
+            RecurFunctionType::Constant(_, number) => self.syntax.number(*number),
+            RecurFunctionType::Successor => {
+                self.syntax.application(&self.syntax.successor(), arguments)
+            }
+            RecurFunctionType::Projection(arguments_count, argument_number) => {
+                self.syntax.application(
+                    &self.syntax.projection(*arguments_count, *argument_number),
+                    arguments,
+                )
+            }
+            RecurFunctionType::Composition(base_function, functions) => {
+                let results: Vec<String> = functions
+                    .iter()
+                    .map(|function| self.apply(function, arguments))
+                    .collect();
+                self.apply(base_function, &results)
+            }
+            RecurFunctionType::Primitive(_, _) => {
+                self.primitives.push(function);
+                let name = self
+                    .syntax
+                    .name(self.identifier, Some(self.primitives.len()));
+                self.syntax.application(&name, arguments)
+            }
+            RecurFunctionType::Minimization(base_function, max) => {
+                self.minimizations += 1;
+                let index = (self.minimizations > 1).then_some(self.minimizations);
+                let variable = self.syntax.variable('z', index);
+                let base_arguments = [arguments.to_vec(), vec![variable.clone()]].concat();
+                let condition = self.apply(base_function, &base_arguments);
+                self.syntax.minimization(&variable, *max, &condition)
+            }
+        }
+    }
+}
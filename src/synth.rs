@@ -0,0 +1,186 @@
+//! Example-driven synthesis of recursive functions.
+//!
+//! Functions are enumerated in order of their size and the first function which gives expected results
+//! on all examples is returned. Search is limited by max size of function, steps of every execution,
+//! time and count of generated nodes, time and nodes are checked while candidates are generated.
+
+use crate::enumerate::{EnumerationBudget, EnumerationOptions, TermEnumerator};
+use crate::equiv::{evaluate, Evaluation, DEFAULT_FUEL};
+use crate::{RecurFunction, RecurFunctionGrammar, RecurFunctionParseError, Rule};
+use pest::Parser;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Example of function result on given arguments.
+pub struct Example {
+    /// arguments of function.
+    pub arguments: Vec<u32>,
+    /// expected result.
+    pub result: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Options of synthesis.
+pub struct SynthesisOptions {
+    /// max size of synthesized function.
+    pub max_size: u32,
+    /// max number of steps of every execution.
+    pub fuel: u64,
+    /// max time of search.
+    pub time_limit: Duration,
+    /// max number of generated nodes of candidate functions, None if count isn't limited.
+    pub max_nodes: Option<u64>,
+    /// options of enumeration of candidate functions.
+    pub enumeration: EnumerationOptions,
+}
+
+impl Default for SynthesisOptions {
+    fn default() -> Self {
+        SynthesisOptions {
+            max_size: 8,
+            fuel: DEFAULT_FUEL,
+            time_limit: Duration::from_secs(10),
+            max_nodes: None,
+            enumeration: EnumerationOptions::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Result of synthesis.
+pub enum Synthesis {
+    /// the smallest function which matches all examples.
+    Found(RecurFunction),
+    /// no function up to max size matches all examples.
+    NotFound,
+    /// time ran out before function was found, contains size which was searched.
+    OutOfTime(u32),
+    /// max number of generated nodes was reached before function was found, contains size which was searched.
+    OutOfNodes(u32),
+}
+
+/// Parses examples of one function, for example: f 0 = 1; f 3 = 4.
+///
+/// # Arguments
+///
+/// * `input` - string which includes examples.
+///
+/// # Returns
+///
+/// Identifier of function with its examples or RecurFunctionParseError wraped into Result
+/// if examples have different identifiers or arguments counts.
+pub fn parse_examples(input: &str) -> Result<(String, Vec<Example>), RecurFunctionParseError> {
+    let pairs = RecurFunctionGrammar::parse(Rule::examples, input)
+        .map_err(|e| RecurFunctionParseError::UndefinedRule(e.to_string()))?
+        .next()
+        .ok_or(RecurFunctionParseError::UndefinedRule(input.to_string()))?
+        .into_inner();
+    let mut identifier: Option<String> = None;
+    let mut examples: Vec<Example> = Vec::new();
+    for pair in pairs {
+        if pair.as_rule() != Rule::example {
+            continue;
+        }
+        let pair_str = pair.as_str();
+        let mut inner_pairs = pair.into_inner();
+        let example_identifier = inner_pairs
+            .next()
+            .ok_or(RecurFunctionParseError::IdentifierExpected(
+                pair_str.to_string(),
+            ))?
+            .as_str();
+        match &identifier {
+            Some(identifier) if identifier != example_identifier => {
+                return Err(RecurFunctionParseError::ExampleIdentifierMismatch(
+                    identifier.clone(),
+                    example_identifier.to_string(),
+                ))
+            }
+            Some(_) => {}
+            None => identifier = Some(example_identifier.to_string()),
+        }
+        let mut integers: Vec<u32> = Vec::new();
+        for inner_pair in inner_pairs {
+            integers.push(inner_pair.as_str().parse::<u32>()?);
+        }
+        let result = integers
+            .pop()
+            .ok_or(RecurFunctionParseError::IntegerExpected(
+                pair_str.to_string(),
+            ))?;
+        if examples
+            .first()
+            .is_some_and(|example| example.arguments.len() != integers.len())
+        {
+            return Err(RecurFunctionParseError::InvalidArgumentsCount(
+                pair_str.to_string(),
+            ));
+        }
+        examples.push(Example {
+            arguments: integers,
+            result,
+        });
+    }
+    let identifier = identifier.ok_or(RecurFunctionParseError::IdentifierExpected(
+        input.to_string(),
+    ))?;
+    Ok((identifier, examples))
+}
+
+/// Searches for the smallest function which gives expected results on all examples.
+///
+/// # Arguments
+///
+/// * `examples` - examples of function, all of them must have the same arguments count.
+/// * `identifier_functions` - parsed identifiers and their function, they can be used in synthesized function.
+/// * `options` - options of synthesis.
+///
+/// # Returns
+///
+/// Result of synthesis.
+pub fn synthesize(
+    examples: &[Example],
+    identifier_functions: &HashMap<String, RecurFunction>,
+    options: &SynthesisOptions,
+) -> Synthesis {
+    let deadline = Instant::now().checked_add(options.time_limit);
+    let out_of_time = || deadline.is_some_and(|deadline| Instant::now() >= deadline);
+    let arguments_count = examples
+        .first()
+        .map(|example| example.arguments.len() as u32)
+        .unwrap_or(0);
+    let mut enumeration = options.enumeration.clone();
+    enumeration.definitions.extend(
+        identifier_functions
+            .iter()
+            .map(|(identifier, function)| (identifier.clone(), function.clone())),
+    );
+    let enumerator = TermEnumerator::with_budget(
+        enumeration,
+        EnumerationBudget {
+            deadline,
+            max_nodes: options.max_nodes,
+        },
+    );
+    for size in 1..=options.max_size {
+        for function in enumerator.terms(arguments_count, size) {
+            if out_of_time() {
+                return Synthesis::OutOfTime(size);
+            }
+            if examples.iter().all(|example| {
+                evaluate(&function, &example.arguments, options.fuel)
+                    == Evaluation::Defined(example.result)
+            }) {
+                return Synthesis::Found(function);
+            }
+        }
+        if enumerator.is_exhausted() {
+            return match out_of_time() {
+                true => Synthesis::OutOfTime(size),
+                false => Synthesis::OutOfNodes(size),
+            };
+        }
+    }
+    Synthesis::NotFound
+}
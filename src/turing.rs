@@ -0,0 +1,482 @@
+//! Compilation of recursive functions into single-tape Turing machines and their simulation.
+//!
+//! Functions are compiled into Unlimited Register Machine programs first and every instruction becomes part of machine.
+//! Tape contains registers in unary encoding separated by '#': #11#1#...#, so arguments 2 and 1 are given as #11#1#.
+//! Machine starts on the leftmost '#'. Value of register is count of '1' in its block,
+//! cleared registers keep '0' in place of their ones and incremented registers refill them first.
+//! When machine stops in accepting state, tape contains only the first register which is the result.
+
+use crate::urm::{self, Instruction, UrmProgram};
+use crate::RecurFunction;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+/// Blank symbol of tape.
+pub const BLANK: char = '_';
+/// Symbol of one in unary encoding.
+const ONE: char = '1';
+/// Symbol of one which was removed from register.
+const HOLE: char = '0';
+/// Symbol which separates registers.
+const SEPARATOR: char = '#';
+/// Symbol of one which is temporarily marked while registers are copied or compared.
+const MARK: char = 'm';
+/// Symbols which can be found inside registers part of tape.
+const TAPE_SYMBOLS: [char; 4] = [ONE, HOLE, SEPARATOR, MARK];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Movements of head.
+pub enum Movement {
+    /// one cell to the left.
+    Left,
+    /// one cell to the right.
+    Right,
+    /// no movement.
+    Stay,
+}
+
+impl fmt::Display for Movement {
+    /// Formats movement as '<', '>' or '-'.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Movement::Left => write!(f, "<"),
+            Movement::Right => write!(f, ">"),
+            Movement::Stay => write!(f, "-"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Transition of machine.
+pub struct Transition {
+    /// next state.
+    pub state: usize,
+    /// symbol written on tape.
+    pub symbol: char,
+    /// movement of head.
+    pub movement: Movement,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Single-tape Turing machine which stops when it has no transition for its state and read symbol.
+pub struct TuringMachine {
+    /// names of states.
+    pub states: Vec<String>,
+    /// initial state.
+    pub start: usize,
+    /// accepting state.
+    pub accept: usize,
+    /// transitions by state and read symbol.
+    pub transitions: HashMap<(usize, char), Transition>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Result of simulation of machine which stopped.
+pub struct Simulation {
+    /// content of tape without leading and trailing blanks.
+    pub tape: String,
+    /// count of executed transitions.
+    pub steps: u64,
+    /// whether machine stopped in accepting state.
+    pub accepted: bool,
+}
+
+impl TuringMachine {
+    /// Simulates machine on given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - initial content of tape, head starts on its first symbol.
+    /// * `fuel` - max number of executed transitions.
+    ///
+    /// # Returns
+    ///
+    /// Some(Simulation) if machine stopped, None if transitions ran out.
+    pub fn simulate(&self, input: &str, fuel: u64) -> Option<Simulation> {
+        let mut tape: VecDeque<char> = input.chars().collect();
+        if tape.is_empty() {
+            tape.push_back(BLANK);
+        }
+        let mut head = 0;
+        let mut state = self.start;
+        let mut steps = 0;
+        while let Some(transition) = self.transitions.get(&(state, tape[head])) {
+            if steps == fuel {
+                return None;
+            }
+            steps += 1;
+            tape[head] = transition.symbol;
+            state = transition.state;
+            match transition.movement {
+                Movement::Left if head == 0 => tape.push_front(BLANK),
+                Movement::Left => head -= 1,
+                Movement::Right => {
+                    head += 1;
+                    if head == tape.len() {
+                        tape.push_back(BLANK);
+                    }
+                }
+                Movement::Stay => {}
+            }
+        }
+        let tape: String = tape.into_iter().collect();
+        Some(Simulation {
+            tape: tape.trim_matches(BLANK).to_string(),
+            steps,
+            accepted: state == self.accept,
+        })
+    }
+
+    /// Exports machine as transition table in format of turingmachinesimulator.com:
+    /// header with name, initial and accepting states and then every transition as two lines,
+    /// state with read symbol and next state with written symbol and movement.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - name of machine.
+    ///
+    /// # Returns
+    ///
+    /// Transition table.
+    pub fn export(&self, name: &str) -> String {
+        let mut table = format!(
+            "name: {name}\ninit: {}\naccept: {}\n",
+            self.states[self.start], self.states[self.accept]
+        );
+        let mut transitions: Vec<(&(usize, char), &Transition)> = self.transitions.iter().collect();
+        transitions.sort_by_key(|((state, symbol), _)| (*state, *symbol));
+        for ((state, symbol), transition) in transitions {
+            table.push_str(&format!(
+                "\n{},{symbol}\n{},{},{}\n",
+                self.states[*state],
+                self.states[transition.state],
+                transition.symbol,
+                transition.movement
+            ));
+        }
+        table
+    }
+}
+
+/// Encodes arguments as initial content of tape.
+///
+/// # Arguments
+///
+/// * `arguments` - arguments of function.
+///
+/// # Returns
+///
+/// Registers with arguments in unary encoding separated by '#'.
+pub fn encode_arguments(arguments: &[u32]) -> String {
+    let mut tape = String::from(SEPARATOR);
+    for argument in arguments {
+        tape.push_str(&ONE.to_string().repeat(*argument as usize));
+        tape.push(SEPARATOR);
+    }
+    tape
+}
+
+/// Decodes result from content of tape.
+///
+/// # Arguments
+///
+/// * `tape` - content of tape.
+///
+/// # Returns
+///
+/// Some(u32) with count of ones in the first register or None if tape has no registers.
+pub fn decode_result(tape: &str) -> Option<u32> {
+    let mut blocks = tape.strip_prefix(SEPARATOR)?.split(SEPARATOR);
+    let first_block = blocks.next().filter(|_| tape[1..].contains(SEPARATOR))?;
+    Some(first_block.chars().filter(|symbol| *symbol == ONE).count() as u32)
+}
+
+/// Runs machine on given arguments.
+///
+/// # Arguments
+///
+/// * `machine` - machine compiled from function.
+/// * `arguments` - arguments of function.
+/// * `fuel` - max number of executed transitions.
+///
+/// # Returns
+///
+/// Some(u32) with result and count of executed transitions if machine accepted, None if transitions ran out.
+pub fn run(machine: &TuringMachine, arguments: &[u32], fuel: u64) -> Option<(u32, u64)> {
+    let simulation = machine.simulate(&encode_arguments(arguments), fuel)?;
+    if !simulation.accepted {
+        return None;
+    }
+    Some((decode_result(&simulation.tape)?, simulation.steps))
+}
+
+/// Compiles recursive function into Turing machine.
+///
+/// # Arguments
+///
+/// * `function` - recursive function.
+///
+/// # Returns
+///
+/// Machine which computes function.
+pub fn compile(function: &RecurFunction) -> TuringMachine {
+    from_urm(&urm::compile(function))
+}
+
+/// Compiles Unlimited Register Machine program into Turing machine.
+///
+/// # Arguments
+///
+/// * `program` - Unlimited Register Machine program.
+///
+/// # Returns
+///
+/// Machine which computes the same function as program.
+pub fn from_urm(program: &UrmProgram) -> TuringMachine {
+    let mut builder = Builder::default();
+    let start = builder.state("start");
+    let accept = builder.state("accept");
+    let instructions: Vec<usize> = (1..=program.instructions.len() + 1)
+        .map(|number| builder.state(&format!("i{number}")))
+        .collect();
+    let end = instructions[program.instructions.len()];
+    let registers_count = program.registers_count();
+    let arguments_count = program.arguments_count as usize;
+    let mut current = start;
+    for _ in 0..=arguments_count {
+        let next = builder.new_state();
+        builder.add(current, ONE, current, ONE, Movement::Right);
+        builder.add(current, SEPARATOR, next, SEPARATOR, Movement::Right);
+        current = next;
+    }
+    for _ in arguments_count..registers_count {
+        let next = builder.new_state();
+        builder.add(current, BLANK, next, SEPARATOR, Movement::Right);
+        current = next;
+    }
+    let rewind = builder.new_state();
+    builder.add(current, BLANK, rewind, BLANK, Movement::Left);
+    builder.rewind(rewind, instructions[0]);
+    for (i, instruction) in program.instructions.iter().enumerate() {
+        let (from, next) = (instructions[i], instructions[i + 1]);
+        match *instruction {
+            Instruction::Zero(register) => builder.zero(from, register, next),
+            Instruction::Successor(register) => builder.successor(from, register, next),
+            Instruction::Transfer(first, second) if first == second => {
+                builder.add(from, SEPARATOR, next, SEPARATOR, Movement::Stay)
+            }
+            Instruction::Transfer(first, second) => builder.transfer(from, first, second, next),
+            Instruction::Jump(first, second, target) => {
+                let target = target
+                    .checked_sub(1)
+                    .and_then(|index| instructions.get(index).copied())
+                    .unwrap_or(end);
+                if first == second {
+                    builder.add(from, SEPARATOR, target, SEPARATOR, Movement::Stay);
+                } else {
+                    builder.jump(from, first, second, target, next);
+                }
+            }
+        }
+    }
+    let erase = builder.new_state();
+    let rewind = builder.new_state();
+    builder.go_to_register(end, 1, erase);
+    builder.add(erase, ONE, erase, ONE, Movement::Right);
+    builder.add(erase, HOLE, erase, HOLE, Movement::Right);
+    let erase_rest = builder.new_state();
+    builder.add(erase, SEPARATOR, erase_rest, SEPARATOR, Movement::Right);
+    for symbol in TAPE_SYMBOLS {
+        builder.add(erase_rest, symbol, erase_rest, BLANK, Movement::Right);
+    }
+    builder.add(erase_rest, BLANK, rewind, BLANK, Movement::Left);
+    builder.rewind(rewind, accept);
+    TuringMachine {
+        states: builder.states,
+        start,
+        accept,
+        transitions: builder.transitions,
+    }
+}
+
+/// Builder of machine which composes parts of machine by their initial and final states.
+/// Parts which work with registers start and finish with head on the leftmost '#'.
+#[derive(Default)]
+struct Builder {
+    /// names of states.
+    states: Vec<String>,
+    /// transitions by state and read symbol.
+    transitions: HashMap<(usize, char), Transition>,
+}
+
+impl Builder {
+    /// Creates state with given name.
+    fn state(&mut self, name: &str) -> usize {
+        self.states.push(name.to_string());
+        self.states.len() - 1
+    }
+
+    /// Creates state with generated name.
+    fn new_state(&mut self) -> usize {
+        let name = format!("q{}", self.states.len());
+        self.state(&name)
+    }
+
+    /// Adds transition.
+    fn add(&mut self, from: usize, read: char, to: usize, write: char, movement: Movement) {
+        self.transitions.insert(
+            (from, read),
+            Transition {
+                state: to,
+                symbol: write,
+                movement,
+            },
+        );
+    }
+
+    /// Moves head to the leftmost '#' from any cell of registers part of tape.
+    fn rewind(&mut self, from: usize, next: usize) {
+        for symbol in TAPE_SYMBOLS {
+            self.add(from, symbol, from, symbol, Movement::Left);
+        }
+        self.add(from, BLANK, next, BLANK, Movement::Right);
+    }
+
+    /// Moves head from the leftmost '#' to the first cell after '#' which starts given register.
+    fn go_to_register(&mut self, from: usize, register: usize, next: usize) {
+        let mut current = from;
+        for number in 1..=register {
+            let following = match number {
+                _ if number == register => next,
+                _ => self.new_state(),
+            };
+            if number > 1 {
+                self.add(current, ONE, current, ONE, Movement::Right);
+                self.add(current, HOLE, current, HOLE, Movement::Right);
+                self.add(current, MARK, current, MARK, Movement::Right);
+            }
+            self.add(current, SEPARATOR, following, SEPARATOR, Movement::Right);
+            current = following;
+        }
+    }
+
+    /// Z(n): replaces all ones of register by holes.
+    fn zero(&mut self, from: usize, register: usize, next: usize) {
+        let clear = self.new_state();
+        let rewind = self.new_state();
+        self.go_to_register(from, register, clear);
+        self.add(clear, ONE, clear, HOLE, Movement::Right);
+        self.add(clear, HOLE, clear, HOLE, Movement::Right);
+        self.add(clear, MARK, clear, MARK, Movement::Right);
+        self.add(clear, SEPARATOR, rewind, SEPARATOR, Movement::Left);
+        self.rewind(rewind, next);
+    }
+
+    /// S(n): fills the first hole of register or inserts one before its closing '#' shifting the rest of tape.
+    fn successor(&mut self, from: usize, register: usize, next: usize) {
+        let find = self.new_state();
+        let rewind = self.new_state();
+        self.go_to_register(from, register, find);
+        self.add(find, ONE, find, ONE, Movement::Right);
+        self.add(find, MARK, find, MARK, Movement::Right);
+        self.add(find, HOLE, rewind, ONE, Movement::Left);
+        let carries: Vec<(char, usize)> = TAPE_SYMBOLS
+            .iter()
+            .map(|symbol| (*symbol, self.new_state()))
+            .collect();
+        let carry_state = |symbol: char| {
+            carries
+                .iter()
+                .find(|(carried, _)| *carried == symbol)
+                .map(|(_, state)| *state)
+                .expect("carried symbol is tape symbol")
+        };
+        self.add(
+            find,
+            SEPARATOR,
+            carry_state(SEPARATOR),
+            ONE,
+            Movement::Right,
+        );
+        for (carried, carry) in carries.iter() {
+            for symbol in TAPE_SYMBOLS {
+                self.add(
+                    *carry,
+                    symbol,
+                    carry_state(symbol),
+                    *carried,
+                    Movement::Right,
+                );
+            }
+            self.add(*carry, BLANK, rewind, *carried, Movement::Left);
+        }
+        self.rewind(rewind, next);
+    }
+
+    /// Restores all marked ones of tape.
+    fn unmark(&mut self, from: usize, next: usize) {
+        let rewind = self.new_state();
+        self.add(from, MARK, from, ONE, Movement::Right);
+        self.add(from, ONE, from, ONE, Movement::Right);
+        self.add(from, HOLE, from, HOLE, Movement::Right);
+        self.add(from, SEPARATOR, from, SEPARATOR, Movement::Right);
+        self.add(from, BLANK, rewind, BLANK, Movement::Left);
+        self.rewind(rewind, next);
+    }
+
+    /// Marks the first unmarked one of register, continues to marked state
+    /// or to exhausted state if register has no unmarked ones with head in any cell.
+    fn mark(&mut self, from: usize, register: usize, marked: usize, exhausted: usize) {
+        let find = self.new_state();
+        self.go_to_register(from, register, find);
+        self.add(find, HOLE, find, HOLE, Movement::Right);
+        self.add(find, MARK, find, MARK, Movement::Right);
+        self.add(find, ONE, marked, MARK, Movement::Left);
+        self.add(find, SEPARATOR, exhausted, SEPARATOR, Movement::Left);
+    }
+
+    /// T(m, n): clears the second register and increments it for every marked one of the first register.
+    fn transfer(&mut self, from: usize, first: usize, second: usize, next: usize) {
+        let copy = self.new_state();
+        let marked = self.new_state();
+        let increment = self.new_state();
+        let exhausted = self.new_state();
+        let unmark = self.new_state();
+        self.zero(from, second, copy);
+        self.mark(copy, first, marked, exhausted);
+        self.rewind(marked, increment);
+        self.successor(increment, second, copy);
+        self.rewind(exhausted, unmark);
+        self.unmark(unmark, next);
+    }
+
+    /// J(m, n, q): marks ones of both registers in turns until one of them has no unmarked ones.
+    fn jump(&mut self, from: usize, first: usize, second: usize, target: usize, next: usize) {
+        let first_marked = self.new_state();
+        let second_from = self.new_state();
+        let second_marked = self.new_state();
+        let first_exhausted = self.new_state();
+        let second_exhausted = self.new_state();
+        let check_from = self.new_state();
+        let check = self.new_state();
+        let check_marked = self.new_state();
+        let check_exhausted = self.new_state();
+        let equal = self.new_state();
+        let unequal = self.new_state();
+        self.mark(from, first, first_marked, first_exhausted);
+        self.rewind(first_marked, second_from);
+        self.mark(second_from, second, second_marked, second_exhausted);
+        self.rewind(second_marked, from);
+        self.rewind(second_exhausted, unequal);
+        self.rewind(first_exhausted, check_from);
+        self.go_to_register(check_from, second, check);
+        self.add(check, HOLE, check, HOLE, Movement::Right);
+        self.add(check, MARK, check, MARK, Movement::Right);
+        self.add(check, ONE, check_marked, ONE, Movement::Left);
+        self.add(check, SEPARATOR, check_exhausted, SEPARATOR, Movement::Left);
+        self.rewind(check_marked, unequal);
+        self.rewind(check_exhausted, equal);
+        self.unmark(equal, target);
+        self.unmark(unequal, next);
+    }
+}
@@ -0,0 +1,259 @@
+//! Compilation of recursive functions into Unlimited Register Machine programs and their interpretation.
+//!
+//! Machine has registers R1, R2, ... which contain natural numbers and instructions Z(n), S(n), T(m, n) and J(m, n, q).
+//! Registers and instructions are numbered from 1. Program gets arguments in registers R1, ..., Rk,
+//! other registers contain zero, result is in register R1 when program stops by going past its last instruction.
+//! Undefined results are computed by programs which never stop.
+
+use crate::{RecurFunction, RecurFunctionType};
+use std::fmt;
+
+/// Constants which are greater than this number are built by doubling instead of successive increments.
+const INCREMENTS_MAX: u32 = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Instructions of Unlimited Register Machine.
+pub enum Instruction {
+    /// Z(n): sets register n to zero.
+    Zero(usize),
+    /// S(n): increments register n.
+    Successor(usize),
+    /// T(m, n): copies register m into register n.
+    Transfer(usize, usize),
+    /// J(m, n, q): jumps to instruction q if registers m and n are equal.
+    Jump(usize, usize, usize),
+}
+
+impl fmt::Display for Instruction {
+    /// Formats instruction, for example: J(1, 2, 5).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Zero(register) => write!(f, "Z({register})"),
+            Instruction::Successor(register) => write!(f, "S({register})"),
+            Instruction::Transfer(from, to) => write!(f, "T({from}, {to})"),
+            Instruction::Jump(first, second, target) => {
+                write!(f, "J({first}, {second}, {target})")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Program of Unlimited Register Machine.
+pub struct UrmProgram {
+    /// instructions of program.
+    pub instructions: Vec<Instruction>,
+    /// count of arguments which program gets in first registers.
+    pub arguments_count: u32,
+}
+
+impl UrmProgram {
+    /// Returns count of registers which are used by program.
+    pub fn registers_count(&self) -> usize {
+        self.instructions
+            .iter()
+            .map(|instruction| match instruction {
+                Instruction::Zero(register) | Instruction::Successor(register) => *register,
+                Instruction::Transfer(first, second) | Instruction::Jump(first, second, _) => {
+                    *first.max(second)
+                }
+            })
+            .max()
+            .unwrap_or(0)
+            .max(self.arguments_count as usize)
+            .max(1)
+    }
+}
+
+impl fmt::Display for UrmProgram {
+    /// Formats program with one numbered instruction on every line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, instruction) in self.instructions.iter().enumerate() {
+            writeln!(f, "{}: {instruction}", i + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compiles recursive function into Unlimited Register Machine program.
+///
+/// # Arguments
+///
+/// * `function` - recursive function.
+///
+/// # Returns
+///
+/// Program which computes function.
+pub fn compile(function: &RecurFunction) -> UrmProgram {
+    let arguments_count = function.arguments_count() as usize;
+    let arguments: Vec<usize> = (1..=arguments_count).collect();
+    let mut instructions = Vec::new();
+    emit(
+        function,
+        &arguments,
+        1,
+        arguments_count.max(1) + 1,
+        &mut instructions,
+    );
+    UrmProgram {
+        instructions,
+        arguments_count: function.arguments_count(),
+    }
+}
+
+/// Emits instructions which compute function and put its result into output register.
+/// Output register is written only after all arguments are read, so it can be one of argument registers.
+///
+/// # Arguments
+///
+/// * `function` - recursive function.
+/// * `arguments` - registers which contain arguments of function.
+/// * `output` - register for result.
+/// * `free` - first register which can be used for intermediate results, all following registers can be used too.
+/// * `instructions` - emitted instructions.
+fn emit(
+    function: &RecurFunction,
+    arguments: &[usize],
+    output: usize,
+    free: usize,
+    instructions: &mut Vec<Instruction>,
+) {
+    let arguments = match function.arguments_count() {
+        0 => &[],
+        _ => arguments,
+    };
+    match function.function_type() {
+        RecurFunctionType::Zero(_) => instructions.push(Instruction::Zero(output)),
+        RecurFunctionType::Constant(_, number) => {
+            emit_constant(*number, output, free, instructions)
+        }
+        RecurFunctionType::Successor => {
+            instructions.push(Instruction::Transfer(arguments[0], output));
+            instructions.push(Instruction::Successor(output));
+        }
+        RecurFunctionType::Projection(_, argument_number) => {
+            instructions.push(Instruction::Transfer(
+                arguments[*argument_number as usize - 1],
+                output,
+            ));
+        }
+        RecurFunctionType::Composition(base_function, functions) => {
+            let results: Vec<usize> = (free..free + functions.len()).collect();
+            let free = free + functions.len();
+            for (function, result) in functions.iter().zip(results.iter()) {
+                emit(function, arguments, *result, free, instructions);
+            }
+            emit(base_function, &results, output, free, instructions);
+        }
+        RecurFunctionType::Primitive(base_function, step_function) => {
+            let (counter_max, base_arguments) = arguments
+                .split_last()
+                .expect("primitive function has at least one argument");
+            let (value, counter) = (free, free + 1);
+            let free = free + 2;
+            emit(base_function, base_arguments, value, free, instructions);
+            instructions.push(Instruction::Zero(counter));
+            let loop_start = instructions.len() + 1;
+            let exit_jump = instructions.len();
+            instructions.push(Instruction::Jump(counter, *counter_max, 0));
+            let mut step_arguments = base_arguments.to_vec();
+            step_arguments.push(counter);
+            step_arguments.push(value);
+            emit(step_function, &step_arguments, value, free, instructions);
+            instructions.push(Instruction::Successor(counter));
+            instructions.push(Instruction::Jump(counter, counter, loop_start));
+            instructions[exit_jump] =
+                Instruction::Jump(counter, *counter_max, instructions.len() + 1);
+            instructions.push(Instruction::Transfer(value, output));
+        }
+        RecurFunctionType::Minimization(base_function, max) => {
+            let (tries, value, zero, limit) = (free, free + 1, free + 2, free + 3);
+            let free = free + 4;
+            emit_constant(*max, limit, free, instructions);
+            instructions.push(Instruction::Zero(tries));
+            instructions.push(Instruction::Zero(zero));
+            let loop_start = instructions.len() + 1;
+            let mut base_arguments = arguments.to_vec();
+            base_arguments.push(tries);
+            emit(base_function, &base_arguments, value, free, instructions);
+            let found_jump = instructions.len();
+            instructions.push(Instruction::Jump(value, zero, 0));
+            let limit_jump = instructions.len();
+            instructions.push(Instruction::Jump(tries, limit, 0));
+            instructions.push(Instruction::Successor(tries));
+            instructions.push(Instruction::Jump(zero, zero, loop_start));
+            let diverge = instructions.len() + 1;
+            instructions.push(Instruction::Jump(zero, zero, diverge));
+            instructions[limit_jump] = Instruction::Jump(tries, limit, diverge);
+            instructions[found_jump] = Instruction::Jump(value, zero, instructions.len() + 1);
+            instructions.push(Instruction::Transfer(tries, output));
+        }
+    }
+}
+
+/// Emits instructions which put number into output register,
+/// big numbers are built from their binary digits by doubling.
+///
+/// # Arguments
+///
+/// * `number` - number to put.
+/// * `output` - register for number.
+/// * `free` - first register which can be used for intermediate results.
+/// * `instructions` - emitted instructions.
+fn emit_constant(number: u32, output: usize, free: usize, instructions: &mut Vec<Instruction>) {
+    instructions.push(Instruction::Zero(output));
+    if number <= INCREMENTS_MAX {
+        for _ in 0..number {
+            instructions.push(Instruction::Successor(output));
+        }
+        return;
+    }
+    let (copy, counter) = (free, free + 1);
+    for bit in (0..u32::BITS - number.leading_zeros()).rev() {
+        instructions.push(Instruction::Transfer(output, copy));
+        instructions.push(Instruction::Zero(counter));
+        let loop_start = instructions.len() + 1;
+        instructions.push(Instruction::Jump(counter, copy, loop_start + 4));
+        instructions.push(Instruction::Successor(output));
+        instructions.push(Instruction::Successor(counter));
+        instructions.push(Instruction::Jump(counter, counter, loop_start));
+        if number >> bit & 1 == 1 {
+            instructions.push(Instruction::Successor(output));
+        }
+    }
+}
+
+/// Runs Unlimited Register Machine program on given arguments using limited number of steps.
+///
+/// # Arguments
+///
+/// * `program` - program to run.
+/// * `arguments` - arguments which are put into first registers.
+/// * `fuel` - max number of executed instructions.
+///
+/// # Returns
+///
+/// Some(u32) with content of first register if program stopped, None if steps ran out or number overflowed.
+pub fn run(program: &UrmProgram, arguments: &[u32], fuel: u64) -> Option<u32> {
+    let mut registers = vec![0u32; program.registers_count().max(arguments.len()) + 1];
+    registers[1..=arguments.len()].copy_from_slice(arguments);
+    let mut current: usize = 1;
+    let mut fuel = fuel;
+    while let Some(instruction) = program.instructions.get(current - 1) {
+        fuel = fuel.checked_sub(1)?;
+        current += 1;
+        match *instruction {
+            Instruction::Zero(register) => registers[register] = 0,
+            Instruction::Successor(register) => {
+                registers[register] = registers[register].checked_add(1)?
+            }
+            Instruction::Transfer(from, to) => registers[to] = registers[from],
+            Instruction::Jump(first, second, target) => {
+                if registers[first] == registers[second] {
+                    current = target;
+                }
+            }
+        }
+    }
+    Some(registers[1])
+}
@@ -0,0 +1,88 @@
+use recur_func_parser::classify::*;
+use recur_func_parser::*;
+
+mod classify_tests {
+    use super::*;
+
+    #[test]
+    fn primitive_recursive_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "addition = [$p1.1, ($s:$p3.3)];
+            multiplication = [$z , (addition: $p3.3,$p3.1)];",
+        )?;
+        let classes = classify_functions(&identifier_functions);
+        assert_eq!(classes["addition"], RecurFunctionClass::PrimitiveRecursive);
+        assert!(classes["multiplication"].is_primitive_recursive());
+        assert!(classes["multiplication"].is_total());
+
+        Ok(())
+    }
+
+    #[test]
+    fn bounded_minimization_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "subtract1 = [$z,$p2.1];
+            subtraction = [$p1.1, (subtract1:$p3.3)];
+            root = {subtraction, 100};
+            useRoot = ($s : root);
+            zeroRoot = {($z : $p2.1), 10};",
+        )?;
+        let classes = classify_functions(&identifier_functions);
+        assert_eq!(
+            classes["root"],
+            RecurFunctionClass::BoundedMinimization(vec![PartialMinimization {
+                identifier: "root".to_string(),
+                function: "{subtraction, 100}".to_string(),
+            }])
+        );
+        assert_eq!(
+            classes["useRoot"],
+            RecurFunctionClass::BoundedMinimization(vec![PartialMinimization {
+                identifier: "root".to_string(),
+                function: "{subtraction, 100}".to_string(),
+            }])
+        );
+        assert!(!classes["useRoot"].is_total());
+        assert!(!classes["zeroRoot"].is_primitive_recursive());
+        assert!(classes["zeroRoot"].is_total());
+
+        Ok(())
+    }
+
+    #[test]
+    fn repeated_minimization_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "root = {($s:$p2.2), 5};
+            twice = ($p2.1 : root, root);",
+        )?;
+        let class = classify("twice", &identifier_functions["twice"]);
+        assert_eq!(
+            class,
+            RecurFunctionClass::BoundedMinimization(vec![PartialMinimization {
+                identifier: "root".to_string(),
+                function: "{($s : $p2.2), 5}".to_string(),
+            }])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn identifier_equality_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "addition = [$p1.1, ($s:$p3.3)];
+            sum = addition;
+            copy = [$p1.1, ($s:$p3.3)];
+            double = (addition : $p1.1, $p1.1);
+            doubleSum = (sum : $p1.1, $p1.1);",
+        )?;
+        assert_eq!(identifier_functions["sum"], identifier_functions["copy"]);
+        assert_eq!(
+            identifier_functions["double"],
+            identifier_functions["doubleSum"]
+        );
+        assert_eq!(identifier_functions["sum"].identifier(), Some("addition"));
+
+        Ok(())
+    }
+}
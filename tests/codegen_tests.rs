@@ -0,0 +1,389 @@
+use recur_func_parser::codegen::*;
+use recur_func_parser::*;
+use std::process::Command;
+
+mod common;
+
+mod codegen_tests {
+    use super::*;
+
+    #[test]
+    fn rust_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "addition = [$p1.1, ($s:$p3.3)];
+            multiplication = [$z , (addition: $p3.3,$p3.1)];",
+        )?;
+        let code = generate(&identifier_functions, Language::Rust);
+        assert!(code.ends_with(
+            "/// addition = [$p1.1, ($s : $p3.3)];
+pub fn addition(x_1: u64, x_2: u64) -> Option<u64> {
+    let mut v_1 = x_1;
+    for v_2 in 0..x_2 {
+        let v_3 = v_1.checked_add(1)?;
+        v_1 = v_3;
+    }
+    Some(v_1)
+}
+
+/// multiplication = [$z1, (addition : $p3.3, $p3.1)];
+pub fn multiplication(x_1: u64, x_2: u64) -> Option<u64> {
+    let mut v_1 = 0u64;
+    for v_2 in 0..x_2 {
+        let v_3 = addition(v_1, x_1)?;
+        v_1 = v_3;
+    }
+    Some(v_1)
+}
+"
+        ));
+
+        Ok(())
+    }
+
+    /// Calls of every function on small arguments with expected output in format of Rust Option<u64>.
+    fn calls(
+        identifier_functions: &std::collections::HashMap<String, RecurFunction>,
+    ) -> Vec<(String, String, String)> {
+        let mut identifiers: Vec<&String> = identifier_functions.keys().collect();
+        identifiers.sort();
+        let mut calls = Vec::new();
+        for identifier in identifiers {
+            let function = &identifier_functions[identifier];
+            for arguments in common::samples(function.arguments_count()) {
+                let arguments_list: Vec<String> = arguments
+                    .iter()
+                    .map(|argument| argument.to_string())
+                    .collect();
+                calls.push((
+                    identifier.clone(),
+                    arguments_list.join(", "),
+                    format!("{:?}\n", execute(function, &arguments).map(u64::from)),
+                ));
+            }
+        }
+        calls
+    }
+
+    /// Compiles and runs program and compares its output.
+    fn compile_and_run(
+        compiler: &str,
+        compiler_arguments: &[&str],
+        file_name: &str,
+        program: String,
+        expected: String,
+    ) -> anyhow::Result<()> {
+        let directory = std::path::Path::new(env!("CARGO_TARGET_TMPDIR"));
+        let source = directory.join(file_name);
+        let binary = directory.join(file_name.replace('.', "_"));
+        std::fs::write(&source, program)?;
+        let compilation = Command::new(compiler)
+            .args(compiler_arguments)
+            .arg("-o")
+            .arg(&binary)
+            .arg(&source)
+            .output()?;
+        assert!(
+            compilation.status.success(),
+            "{}",
+            String::from_utf8_lossy(&compilation.stderr)
+        );
+        let output = Command::new(&binary).output()?;
+        assert_eq!(String::from_utf8(output.stdout)?, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rust_compile_test() -> anyhow::Result<()> {
+        let identifier_functions = common::functions()?;
+        let mut program = generate(&identifier_functions, Language::Rust);
+        program.push_str("\nfn main() {\n");
+        let mut expected = String::new();
+        for (identifier, arguments, output) in calls(&identifier_functions) {
+            let name = match identifier.as_str() {
+                "const" => "const_",
+                identifier => identifier,
+            };
+            program.push_str(&format!("    println!(\"{{:?}}\", {name}({arguments}));\n"));
+            expected.push_str(&output);
+        }
+        program.push_str("}\n");
+        compile_and_run(
+            "rustc",
+            &["--edition=2021"],
+            "codegen_rust.rs",
+            program,
+            expected,
+        )
+    }
+
+    #[test]
+    fn rust_overflow_test() -> anyhow::Result<()> {
+        let identifier_functions =
+            parse_recur_functions("successor = $s; addition = [$p1.1, ($s:$p3.3)];")?;
+        let mut program = generate(&identifier_functions, Language::Rust);
+        program.push_str(
+            "
+fn main() {
+    println!(\"{:?}\", successor(u64::from(u32::MAX)));
+    println!(\"{:?}\", successor(u64::MAX));
+    println!(\"{:?}\", addition(u64::MAX - 1, 1));
+    println!(\"{:?}\", addition(u64::MAX, 1));
+}
+",
+        );
+        compile_and_run(
+            "rustc",
+            &["--edition=2021"],
+            "codegen_rust_overflow.rs",
+            program,
+            format!(
+                "Some({})\nNone\nSome({})\nNone\n",
+                u64::from(u32::MAX) + 1,
+                u64::MAX
+            ),
+        )
+    }
+
+    #[test]
+    fn c_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "addition = [$p1.1, ($s:$p3.3)];
+            nullary = ($s:$z);
+            half = {(addition:$p2.2,$p2.2), 20};",
+        )?;
+        let code = generate(&identifier_functions, Language::C);
+        assert!(code.contains(
+            "/* addition = [$p1.1, ($s : $p3.3)]; */
+bool addition(uint64_t x_1, uint64_t x_2, uint64_t *result_) {
+    uint64_t v_1 = x_1;
+    for (uint64_t v_2 = 0; v_2 < x_2; v_2++) {
+        if (v_1 == UINT64_MAX) return false;
+        uint64_t v_3 = v_1 + 1;
+        v_1 = v_3;
+    }
+    *result_ = v_1;
+    return true;
+}
+"
+        ));
+        assert!(code.contains(
+            "/* half = {(addition : $p2.2, $p2.2), 20}; */
+bool half(uint64_t x_1, uint64_t *result_) {
+    uint64_t v_1 = 0;
+    for (uint64_t v_2 = 0; v_2 <= UINT64_C(20); v_2++) {
+        uint64_t v_3;
+        if (!addition(v_2, v_2, &v_3)) return false;
+        if (v_3 == 0) {
+            v_1 = v_2 + 1;
+            break;
+        }
+    }
+    if (v_1 == 0) return false;
+    uint64_t v_4 = v_1 - 1;
+    *result_ = v_4;
+    return true;
+}
+"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn c_compile_test() -> anyhow::Result<()> {
+        let identifier_functions = common::functions()?;
+        let mut program = generate(&identifier_functions, Language::C);
+        program.push_str("\n#include <stdio.h>\n\nint main(void) {\n    uint64_t result;\n");
+        let mut expected = String::new();
+        for (identifier, arguments, output) in calls(&identifier_functions) {
+            let name = match identifier.as_str() {
+                "const" => "const_",
+                identifier => identifier,
+            };
+            let separator = match arguments.is_empty() {
+                true => "",
+                false => ", ",
+            };
+            program.push_str(&format!(
+                "    if ({name}({arguments}{separator}&result)) printf(\"Some(%llu)\\n\", (unsigned long long) result);\n    else printf(\"None\\n\");\n"
+            ));
+            expected.push_str(&output);
+        }
+        program.push_str("    return 0;\n}\n");
+        compile_and_run("cc", &["-std=c99"], "codegen_c.c", program, expected)
+    }
+
+    #[test]
+    fn c_overflow_test() -> anyhow::Result<()> {
+        let identifier_functions =
+            parse_recur_functions("successor = $s; addition = [$p1.1, ($s:$p3.3)];")?;
+        let mut program = generate(&identifier_functions, Language::C);
+        program.push_str(
+            "
+#include <stdio.h>
+
+int main(void) {
+    uint64_t result;
+    printf(\"%d\\n\", successor(UINT64_C(4294967295), &result));
+    printf(\"%llu\\n\", (unsigned long long) result);
+    printf(\"%d\\n\", successor(UINT64_MAX, &result));
+    printf(\"%d\\n\", addition(UINT64_MAX, 1, &result));
+    printf(\"%d\\n\", addition(UINT64_MAX, 0, &result));
+    return 0;
+}
+",
+        );
+        compile_and_run(
+            "cc",
+            &["-std=c99"],
+            "codegen_c_overflow.c",
+            program,
+            "1\n4294967296\n0\n0\n1\n".to_string(),
+        )
+    }
+
+    #[test]
+    fn python_test() -> anyhow::Result<()> {
+        let identifier_functions = common::functions()?;
+        let mut program = generate(&identifier_functions, Language::Python);
+        program.push_str("\n\nfor result in [\n");
+        let mut expected = String::new();
+        for (identifier, arguments, output) in calls(&identifier_functions) {
+            program.push_str(&format!("    {identifier}({arguments}),\n"));
+            expected.push_str(&output);
+        }
+        program.push_str("]:\n    print(\"None\" if result is None else f\"Some({result})\")\n");
+        let output = Command::new("python3").arg("-c").arg(program).output()?;
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert_eq!(String::from_utf8(output.stdout)?, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn python_big_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "successor = $s; addition = [$p1.1, ($s:$p3.3)]; undefined = {($s:$p2.1), 3};",
+        )?;
+        let mut program = generate(&identifier_functions, Language::Python);
+        program.push_str(
+            "
+
+print(successor(2 ** 64))
+print(addition(2 ** 70, 3))
+print(undefined(2))
+",
+        );
+        let output = Command::new("python3").arg("-c").arg(program).output()?;
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert_eq!(
+            String::from_utf8(output.stdout)?,
+            "18446744073709551617\n1180591620717411303427\nNone\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn wasm_test() -> anyhow::Result<()> {
+        let identifier_functions = common::functions()?;
+        let text = generate(&identifier_functions, Language::Wat);
+        assert!(text.contains(
+            "    ;; nullary = ($s : $z);
+    (func $nullary (export \"nullary\") (result i64)
+        (local $v_1 i64)
+        (if (i64.eq (i64.const 0) (i64.const -1)) (then (unreachable)))
+        (local.set $v_1 (i64.add (i64.const 0) (i64.const 1)))
+        (local.get $v_1))
+"
+        ));
+        let mut instance = WasmInstance::new(&identifier_functions)?;
+        common::assert_computes(&identifier_functions, |identifier, _, arguments| {
+            instance
+                .call(identifier, arguments)
+                .and_then(|result| u32::try_from(result).ok())
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn wasm_trap_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "successor = $s;
+            nullary = 7;
+            addition = [$p1.1, ($s:$p3.3)];
+            never = {($s:$p2.2), 20};",
+        )?;
+        let mut instance = WasmInstance::new(&identifier_functions)?;
+        assert_eq!(
+            instance.call("successor", &[u32::MAX]),
+            Some(u64::from(u32::MAX) + 1)
+        );
+        assert_eq!(instance.call_u64("successor", &[u64::MAX]), None);
+        assert_eq!(instance.call_u64("addition", &[u64::MAX, 1]), None);
+        assert_eq!(instance.call("nullary", &[]), Some(7));
+        assert_eq!(instance.call("never", &[1]), None);
+
+        Ok(())
+    }
+
+    /// Instance of WebAssembly module generated from functions.
+    struct WasmInstance {
+        /// store of instance.
+        store: wasmi::Store<()>,
+        /// instantiated module.
+        instance: wasmi::Instance,
+    }
+
+    impl WasmInstance {
+        /// Generates module from functions and instantiates it.
+        fn new(
+            identifier_functions: &std::collections::HashMap<String, RecurFunction>,
+        ) -> anyhow::Result<WasmInstance> {
+            let engine = wasmi::Engine::default();
+            let module = wasmi::Module::new(&engine, &generate_wasm(identifier_functions)[..])?;
+            let mut store = wasmi::Store::new(&engine, ());
+            let instance = wasmi::Linker::<()>::new(&engine)
+                .instantiate(&mut store, &module)?
+                .start(&mut store)?;
+            Ok(WasmInstance { store, instance })
+        }
+
+        /// Calls exported function, None if it traps.
+        fn call(&mut self, identifier: &str, arguments: &[u32]) -> Option<u64> {
+            let arguments: Vec<u64> = arguments
+                .iter()
+                .map(|argument| u64::from(*argument))
+                .collect();
+            self.call_u64(identifier, &arguments)
+        }
+
+        /// Calls exported function with unsigned 64-bit arguments, None if it traps.
+        fn call_u64(&mut self, identifier: &str, arguments: &[u64]) -> Option<u64> {
+            let exported = self
+                .instance
+                .get_func(&self.store, identifier)
+                .unwrap_or_else(|| panic!("{identifier} isn't exported"));
+            let inputs: Vec<wasmi::Val> = arguments
+                .iter()
+                .map(|argument| wasmi::Val::I64(*argument as i64))
+                .collect();
+            let mut outputs = [wasmi::Val::I64(0)];
+            exported
+                .call(&mut self.store, &inputs, &mut outputs)
+                .ok()
+                .and_then(|_| outputs[0].i64())
+                .map(|result| result as u64)
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Fixture shared by tests of backends which compile or translate recursive functions.
+#![allow(dead_code)]
+
+use recur_func_parser::*;
+use std::collections::HashMap;
+
+/// Functions which every backend has to compute as `execute` does, including nullary, constant and partial functions.
+pub const FUNCTIONS: &str = "addition = [$p1.1, ($s:$p3.3)];
+    multiplication = [$z , (addition: $p3.3,$p3.1)];
+    subtract1 = [$z,$p2.1];
+    subtraction = [$p1.1, (subtract1:$p3.3)];
+    subtractionRev = (subtraction : $p2.2, $p2.1);
+    subtractionAbs = (addition: subtraction, subtractionRev);
+    subtractionAbs3 = (subtractionAbs:$p3.1, (addition:$p3.2,$p3.3));
+    subtractionPart = {subtractionAbs3, 10};
+    const = 12;
+    nullary = ($s:$z);
+    first = $p3.1;
+    alias = addition;
+    factorial = [1, (multiplication: ($s:$p2.1), $p2.2)];
+    half = {(subtractionAbs:$p2.1,(addition:$p2.2,$p2.2)), 5};
+    addition4 = (addition: (addition:$p4.1,$p4.2), (addition:$p4.4,$p4.3));";
+
+/// Max sampled value of first and second argument.
+pub const SAMPLE_MAX: u32 = 3;
+
+/// Parses shared functions.
+pub fn functions() -> anyhow::Result<HashMap<String, RecurFunction>> {
+    Ok(parse_recur_functions(FUNCTIONS)?)
+}
+
+/// Samples arguments for every first and second argument up to SAMPLE_MAX,
+/// every next argument is sum of two previous ones, repeated samples of functions with fewer arguments are skipped.
+pub fn samples(arguments_count: u32) -> Vec<Vec<u32>> {
+    let mut samples = Vec::new();
+    for x in 0..=SAMPLE_MAX {
+        for y in 0..=SAMPLE_MAX {
+            let mut arguments = vec![x, y];
+            while arguments.len() < arguments_count as usize {
+                arguments.push(arguments[arguments.len() - 2] + arguments[arguments.len() - 1]);
+            }
+            arguments.truncate(arguments_count as usize);
+            samples.push(arguments);
+        }
+    }
+    samples.dedup();
+    samples
+}
+
+/// Checks that backend computes every function on every sample as `execute` does.
+///
+/// # Arguments
+///
+/// * `identifier_functions` - parsed identifiers and their function.
+/// * `compute` - computes result of function with given identifier on arguments by backend, None if it is undefined.
+pub fn assert_computes(
+    identifier_functions: &HashMap<String, RecurFunction>,
+    mut compute: impl FnMut(&str, &RecurFunction, &[u32]) -> Option<u32>,
+) {
+    let mut identifiers: Vec<&String> = identifier_functions.keys().collect();
+    identifiers.sort();
+    for identifier in identifiers {
+        let function = &identifier_functions[identifier];
+        for arguments in samples(function.arguments_count()) {
+            assert_eq!(
+                compute(identifier, function, &arguments),
+                execute(function, &arguments),
+                "{identifier}{arguments:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use recur_func_parser::*;
+
+mod constant_tests {
+    use super::*;
+
+    #[test]
+    fn basic_constant_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "const0 = $z; const0v2 = ($z : $p2.1); const0v3 = ($z:$p3.1); const2 = ($s:($s:$z));",
+        )?;
+        assert_eq!(identifier_functions["const0"].constant(), Some(0));
+        assert_eq!(identifier_functions["const0v2"].constant(), Some(0));
+        assert_eq!(identifier_functions["const0v3"].constant(), Some(0));
+        assert_eq!(identifier_functions["const2"].constant(), Some(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn composition_constant_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "const1v2 = ($s : ($z : $p2.1));
+            addition = [$p1.1, ($s:$p3.3)];
+            const3v2 = (addition : const1v2, ($s : const1v2));
+            addition2 = (addition : $p2.2, $p2.1);
+            first = ($p2.1 : $p1.1, ($s : $p1.1));",
+        )?;
+        assert_eq!(identifier_functions["const1v2"].constant(), Some(1));
+        assert_eq!(identifier_functions["const3v2"].constant(), Some(3));
+        assert_eq!(identifier_functions["addition"].constant(), None);
+        assert_eq!(
+            identifier_functions["addition2"].abstract_value(),
+            &AbstractValue::Total
+        );
+        assert_eq!(
+            identifier_functions["first"].abstract_value(),
+            &AbstractValue::Argument(1)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn primitive_constant_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "keep = [$z, $p3.3];
+            same = [($s:$z), ($s:($z:$p3.1))];
+            first = [$p1.1, $p3.1];
+            subtract1 = [$z,$p2.1];",
+        )?;
+        assert_eq!(identifier_functions["keep"].constant(), Some(0));
+        assert_eq!(identifier_functions["same"].constant(), Some(1));
+        assert_eq!(
+            identifier_functions["first"].abstract_value(),
+            &AbstractValue::Argument(1)
+        );
+        assert_eq!(identifier_functions["subtract1"].constant(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn minimization_constant_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "zeroFirst = {($z:$p2.1), 10};
+            last = {$p2.2, 10};
+            never = {($s:$p2.2), 10};
+            fromNever = ($z : never);",
+        )?;
+        assert_eq!(identifier_functions["zeroFirst"].constant(), Some(0));
+        assert_eq!(identifier_functions["last"].constant(), Some(0));
+        assert_eq!(
+            identifier_functions["never"].abstract_value(),
+            &AbstractValue::Partial
+        );
+        assert_eq!(
+            identifier_functions["fromNever"].abstract_value(),
+            &AbstractValue::Partial
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn indexed_constant_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "const0v3 = $z3; const5v3 = $c3.5; const7 = $c0.7; addition = [$p1.1, ($s:$p3.3)];
+            plus5 = (addition : $p2.1, $c2.5);",
+        )?;
+        assert_eq!(identifier_functions["const0v3"].arguments_count(), 3);
+        assert_eq!(identifier_functions["const0v3"].constant(), Some(0));
+        assert_eq!(identifier_functions["const5v3"].arguments_count(), 3);
+        assert_eq!(identifier_functions["const5v3"].constant(), Some(5));
+        assert_eq!(identifier_functions["const7"].arguments_count(), 0);
+        assert_eq!(identifier_functions["const7"].constant(), Some(7));
+
+        let query = parse_query("plus5 4 9", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(9));
+
+        assert!(parse_query("const5v3 1 2", &identifier_functions).is_err());
+        assert!(parse_recur_functions("wrong = ($s : $z2, $p2.1);").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn literal_constant_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "const1000 = 1000; addition = [$p1.1, ($s:$p3.3)];
+            plus7 = (addition: $p2.1, 7);
+            addSeven = [7, ($s:$p3.3)];",
+        )?;
+        assert_eq!(identifier_functions["const1000"].arguments_count(), 0);
+        assert_eq!(identifier_functions["const1000"].constant(), Some(1000));
+        assert_eq!(identifier_functions["plus7"].arguments_count(), 2);
+        assert_eq!(identifier_functions["addSeven"].arguments_count(), 2);
+
+        let query = parse_query("const1000", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(1000));
+
+        let query = parse_query("plus7 3 0", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(10));
+
+        let query = parse_query("addSeven 0 5", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(12));
+
+        assert!(parse_recur_functions("wrong = 99999999999;").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn constant_query_test() -> anyhow::Result<()> {
+        let identifier_functions =
+            parse_recur_functions("const0v3 = ($z:$p3.1); const1 = ($s : ($z : $p2.1));")?;
+        let query = parse_query("const0v3 1 2 3", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(0));
+
+        let query = parse_query("const1 4 5", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(1));
+
+        assert!(parse_query("const1 4", &identifier_functions).is_err());
+        assert!(parse_query("const0v3", &identifier_functions).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use recur_func_parser::differential::*;
+use recur_func_parser::equiv::*;
+use recur_func_parser::*;
+
+mod differential_tests {
+    use super::*;
+
+    const FUNCTIONS: &str = "addition = [$p1.1, ($s:$p3.3)];
+        multiplication = [$z , (addition: $p3.3,$p3.1)];
+        subtract1 = [$z,$p2.1];
+        subtraction = [$p1.1, (subtract1:$p3.3)];
+        wrongMultiplication = [$p1.1 , (addition: $p3.3,$p3.1)];
+        subtractionRev = (subtraction : $p2.2, $p2.1);
+        subtractionAbs = (addition: subtraction, subtractionRev);
+        subtractionAbs3 = (subtractionAbs:$p3.1, (addition:$p3.2,$p3.3));
+        subtractionPart = {subtractionAbs3, 100};";
+
+    #[test]
+    fn agree_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        let mut tester = DifferentialTester::new();
+        tester.register("multiplication", |arguments| {
+            arguments[0].checked_mul(arguments[1])
+        });
+        tester.register("subtraction", |arguments| {
+            Some(arguments[0].saturating_sub(arguments[1]))
+        });
+        tester.register("subtractionPart", |arguments| {
+            arguments[0].checked_sub(arguments[1])
+        });
+        let mismatches = tester.test(&identifier_functions, &DifferentialOptions::default())?;
+        assert_eq!(mismatches, vec![]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mismatch_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        let mut tester = DifferentialTester::new();
+        tester.register("wrongMultiplication", |arguments| {
+            arguments[0].checked_mul(arguments[1])
+        });
+        tester.register("subtractionPart", |arguments| {
+            Some(arguments[0].saturating_sub(arguments[1]))
+        });
+        let mismatches = tester.test(&identifier_functions, &DifferentialOptions::default())?;
+        assert_eq!(
+            mismatches,
+            vec![
+                Mismatch {
+                    identifier: "subtractionPart".to_string(),
+                    counterexample: Counterexample {
+                        arguments: vec![0, 1],
+                        first: Evaluation::Defined(0),
+                        second: Evaluation::Undefined,
+                    },
+                },
+                Mismatch {
+                    identifier: "wrongMultiplication".to_string(),
+                    counterexample: Counterexample {
+                        arguments: vec![1, 0],
+                        first: Evaluation::Defined(0),
+                        second: Evaluation::Defined(1),
+                    },
+                },
+            ]
+        );
+        assert_eq!(
+            mismatches[1].to_string(),
+            "wrongMultiplication(1, 0): expected 0, got 1"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn exhausted_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "diverging = {($s:$p3.3), 4000000000}; undefined = {($s:$p2.1), 4000000000};",
+        )?;
+        let mut tester = DifferentialTester::new();
+        tester.register("diverging", |arguments| {
+            arguments[0].checked_mul(arguments[1])
+        });
+        tester.register("undefined", |_| None);
+        let options = DifferentialOptions {
+            cases: 10,
+            fuel: 10_000,
+            ..DifferentialOptions::default()
+        };
+        let mismatches = tester.test(&identifier_functions, &options)?;
+        assert_eq!(
+            mismatches,
+            vec![Mismatch {
+                identifier: "diverging".to_string(),
+                counterexample: Counterexample {
+                    arguments: vec![0, 0],
+                    first: Evaluation::Defined(0),
+                    second: Evaluation::Exhausted,
+                },
+            }]
+        );
+        assert_eq!(
+            mismatches[0].to_string(),
+            "diverging(0, 0): expected 0, got out of fuel"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn undefined_identifier_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        let mut tester = DifferentialTester::new();
+        tester.register("division", |arguments| {
+            arguments[0].checked_div(arguments[1])
+        });
+        assert!(tester
+            .test(&identifier_functions, &DifferentialOptions::default())
+            .is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use recur_func_parser::*;
+
+mod display_tests {
+    use super::*;
+
+    #[test]
+    fn display_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "addition = [$p1.1, ($s:$p3.3)];
+            multiplication = [$z , (addition: $p3.3,$p3.1)];
+            constants = ($p3.1 : $z2, $c2.5, 7);
+            root = {(addition : $p.2, $p2.1), 10};",
+        )?;
+        assert_eq!(
+            identifier_functions["addition"].to_string(),
+            "[$p1.1, ($s : $p3.3)]"
+        );
+        assert_eq!(
+            identifier_functions["multiplication"].to_string(),
+            "[$z1, (addition : $p3.3, $p3.1)]"
+        );
+        assert_eq!(
+            identifier_functions["constants"].to_string(),
+            "($p3.1 : $z2, $c2.5, $c2.7)"
+        );
+        assert_eq!(
+            identifier_functions["root"].to_string(),
+            "{(addition : $p2.2, $p2.1), 10}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn alias_display_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "addition = [$p1.1, ($s:$p3.3)];
+            sum = addition;
+            double = (sum : $p1.1, $p1.1);",
+        )?;
+        assert_eq!(
+            identifier_functions["sum"].to_string(),
+            "[$p1.1, ($s : $p3.3)]"
+        );
+        assert_eq!(
+            identifier_functions["double"].to_string(),
+            "(sum : $p1.1, $p1.1)"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use recur_func_parser::enumerate::*;
+use recur_func_parser::*;
+
+mod enumerate_tests {
+    use super::*;
+
+    #[test]
+    fn leaves_test() -> anyhow::Result<()> {
+        let functions: Vec<String> = enumerate_terms(1, 1, &EnumerationOptions::default())
+            .map(|function| function.to_string())
+            .collect();
+        assert_eq!(functions, vec!["$z1", "$s", "$p1.1"]);
+
+        let options = EnumerationOptions {
+            max_constant: Some(2),
+            ..EnumerationOptions::default()
+        };
+        let functions: Vec<String> = enumerate_terms(0, 1, &options)
+            .map(|function| function.to_string())
+            .collect();
+        assert_eq!(functions, vec!["$z", "0", "1", "2"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn minimization_test() -> anyhow::Result<()> {
+        let functions: Vec<String> = enumerate_terms(1, 2, &EnumerationOptions::default())
+            .map(|function| function.to_string())
+            .collect();
+        assert_eq!(functions, vec!["{$z2, 10}", "{$p2.1, 10}", "{$p2.2, 10}"]);
+
+        let options = EnumerationOptions {
+            minimization_max: None,
+            ..EnumerationOptions::default()
+        };
+        assert_eq!(enumerate_terms(1, 2, &options).count(), 0);
+        let enumerator = TermEnumerator::new(options);
+        for size in 1..=6 {
+            assert!(enumerator
+                .terms(2, size)
+                .all(|function| !function.to_string().contains('{')));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn well_formed_test() -> anyhow::Result<()> {
+        let enumerator = TermEnumerator::new(EnumerationOptions::default());
+        for arguments_count in 0..=2 {
+            for size in 1..=5 {
+                let mut count = 0;
+                for function in enumerator.terms(arguments_count, size) {
+                    let identifier_functions = parse_recur_functions(&format!("f = {function};"))?;
+                    assert_eq!(identifier_functions["f"].arguments_count(), arguments_count);
+                    assert_eq!(identifier_functions["f"].to_string(), function.to_string());
+                    count += 1;
+                }
+                if arguments_count > 0 && size >= 3 {
+                    assert!(count > 0);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn addition_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions("addition = [$p1.1, ($s:$p3.3)];")?;
+        let options = EnumerationOptions {
+            minimization_max: None,
+            ..EnumerationOptions::default()
+        };
+        assert!(enumerate_terms(2, 5, &options)
+            .any(|function| function.to_string() == identifier_functions["addition"].to_string()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn nullary_base_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions("two = 2; f = [two, ($s:$p3.3)];")?;
+        let options = EnumerationOptions {
+            minimization_max: None,
+            definitions: [("two".to_string(), identifier_functions["two"].clone())].into(),
+            ..EnumerationOptions::default()
+        };
+        assert!(enumerate_terms(2, 5, &options)
+            .any(|function| function.to_string() == identifier_functions["f"].to_string()));
+        let functions: Vec<String> = enumerate_terms(2, 3, &options)
+            .map(|function| function.to_string())
+            .collect();
+        assert!(functions.contains(&"[two, $p3.1]".to_string()));
+        assert!(functions.contains(&"[$z1, $p3.1]".to_string()));
+        assert!(!functions.contains(&"[$z, $p3.1]".to_string()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn lazy_test() -> anyhow::Result<()> {
+        let enumerator = TermEnumerator::new(EnumerationOptions::default());
+        let functions: Vec<RecurFunction> = enumerator.terms(3, 40).take(5).collect();
+        assert_eq!(functions.len(), 5);
+        assert!(functions
+            .iter()
+            .all(|function| function.arguments_count() == 3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn budget_test() -> anyhow::Result<()> {
+        let budget = EnumerationBudget {
+            max_nodes: Some(10),
+            ..EnumerationBudget::default()
+        };
+        let enumerator = TermEnumerator::with_budget(EnumerationOptions::default(), budget);
+        assert!(enumerator.terms(2, 6).count() < 10);
+        assert!(enumerator.is_exhausted());
+        assert_eq!(enumerator.terms(1, 1).count(), 0);
+        assert!(!TermEnumerator::new(EnumerationOptions::default()).is_exhausted());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use recur_func_parser::equiv::*;
+use recur_func_parser::*;
+
+mod equiv_tests {
+    use super::*;
+
+    const FUNCTIONS: &str = "addition = [$p1.1, ($s:$p3.3)];
+        additionV2 = (addition : $p2.2, $p2.1);
+        subtract1 = [$z,$p2.1];
+        subtraction = [$p1.1, (subtract1:$p3.3)];
+        wrongSubtraction = [$p1.1, ($p3.3 : $p3.1, $p3.2, $p3.3)];
+        subtractionPart = {(addition: ($p3.2 : $p3.1, $p3.2, $p3.3), ($p3.1 : $p3.1, $p3.2, $p3.3)), 100};
+        loop = {($s:$p2.2), 1000000};";
+
+    #[test]
+    fn equivalent_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        let equivalence = check_equivalence(
+            &identifier_functions["addition"],
+            &identifier_functions["additionV2"],
+            5,
+            DEFAULT_FUEL,
+        )?;
+        assert_eq!(
+            equivalence,
+            Equivalence::Equivalent {
+                checked: 36,
+                exhausted: 0
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn counterexample_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        let equivalence = check_equivalence(
+            &identifier_functions["subtraction"],
+            &identifier_functions["wrongSubtraction"],
+            5,
+            DEFAULT_FUEL,
+        )?;
+        assert_eq!(
+            equivalence,
+            Equivalence::Counterexample(Counterexample {
+                arguments: vec![1, 1],
+                first: Evaluation::Defined(0),
+                second: Evaluation::Defined(1),
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn definedness_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        let equivalence = check_equivalence(
+            &identifier_functions["subtraction"],
+            &identifier_functions["subtractionPart"],
+            5,
+            DEFAULT_FUEL,
+        )?;
+        assert_eq!(
+            equivalence,
+            Equivalence::Counterexample(Counterexample {
+                arguments: vec![0, 1],
+                first: Evaluation::Defined(0),
+                second: Evaluation::Undefined,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fuel_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        assert_eq!(
+            evaluate(&identifier_functions["loop"], &[3], 1000),
+            Evaluation::Exhausted
+        );
+        assert_eq!(
+            evaluate(&identifier_functions["addition"], &[3, 4], 1000),
+            Evaluation::Defined(7)
+        );
+        let equivalence = check_equivalence(
+            &identifier_functions["loop"],
+            &identifier_functions["subtract1"],
+            3,
+            1000,
+        )?;
+        assert_eq!(
+            equivalence,
+            Equivalence::Equivalent {
+                checked: 4,
+                exhausted: 4
+            }
+        );
+
+        assert_eq!(
+            check_equivalence(
+                &identifier_functions["addition"],
+                &identifier_functions["subtract1"],
+                3,
+                1000
+            ),
+            Err(EquivalenceError::ArgumentsCountMismatch(2, 1))
+        );
+
+        Ok(())
+    }
+}
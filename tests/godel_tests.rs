@@ -0,0 +1,82 @@
+use recur_func_parser::godel::*;
+use recur_func_parser::*;
+
+mod godel_tests {
+    use super::*;
+
+    #[test]
+    fn encode_test() -> anyhow::Result<()> {
+        let identifier_functions =
+            parse_recur_functions("successor = $s; zero2 = $z2; first = $p2.1; second = $p2.2;")?;
+        assert_eq!(
+            encode(&identifier_functions["successor"]),
+            BigNat::from(0u32)
+        );
+        assert_eq!(encode(&identifier_functions["zero2"]), BigNat::from(13u32));
+        assert_eq!(encode(&identifier_functions["first"]), BigNat::from(9u32));
+        assert_eq!(encode(&identifier_functions["second"]), BigNat::from(15u32));
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "addition = [$p1.1, ($s:$p3.3)];
+            multiplication = [$z , (addition: $p3.3,$p3.1)];
+            const3 = (addition : 1, 2);
+            subtractionPart = {(addition: $p3.3, $p3.2), 100};
+            empty = ($z:);",
+        )?;
+        for (identifier, function) in identifier_functions.iter() {
+            let number = encode(function);
+            let decoded =
+                decode(&number).ok_or_else(|| anyhow::anyhow!("{identifier} is not decoded"))?;
+            assert_eq!(encode(&decoded), number);
+            assert_eq!(decoded.arguments_count(), function.arguments_count());
+            assert_eq!(decoded.abstract_value(), function.abstract_value());
+            assert_eq!(
+                execute(&decoded, &vec![3, 4]),
+                execute(function, &vec![3, 4])
+            );
+        }
+        assert_eq!(
+            decode(&encode(&identifier_functions["addition"])).map(|function| function.to_string()),
+            Some("[$p1.1, ($s : $p3.3)]".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn ill_formed_test() -> anyhow::Result<()> {
+        // primitive function with successor as step function: 1 + 6 * pair(0, 0) + 4.
+        assert!(decode(&BigNat::from(5u32)).is_none());
+        // minimization of zero function without arguments: 1 + 6 * pair(1, 0) + 5.
+        assert!(decode(&BigNat::from(12u32)).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn enumerate_test() -> anyhow::Result<()> {
+        let functions = enumerate(2, 4);
+        let numbers: Vec<BigNat> = functions.iter().map(|(number, _)| number.clone()).collect();
+        assert_eq!(
+            numbers,
+            vec![
+                BigNat::from(9u32),
+                BigNat::from(13u32),
+                BigNat::from(15u32),
+                BigNat::from(20u32)
+            ]
+        );
+        let functions: Vec<String> = functions
+            .iter()
+            .map(|(_, function)| function.to_string())
+            .collect();
+        assert_eq!(functions, vec!["$p2.1", "$z2", "$p2.2", "$c2.0"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+use recur_func_parser::equiv::*;
+use recur_func_parser::grade::*;
+use recur_func_parser::*;
+
+mod grade_tests {
+    use super::*;
+
+    const REFERENCE: &str = "addition = [$p1.1, ($s:$p3.3)];
+        multiplication = [$z , (addition: $p3.3,$p3.1)];
+        subtract1 = [$z,$p2.1];
+        subtraction = [$p1.1, (subtract1:$p3.3)];";
+
+    const SUBMISSION: &str = "addition = [$p1.1, ($s:$p3.3)];
+        multiplication = [$z , (addition: $p3.1,$p3.3)];
+        subtraction = [$p1.1, ($p3.3 : $p3.1, $p3.2, $p3.3)];
+        subtract1 = $p2.1;";
+
+    #[test]
+    fn grade_test() -> anyhow::Result<()> {
+        let reference = parse_recur_functions(REFERENCE)?;
+        let submission = parse_recur_functions(SUBMISSION)?;
+        let report = grade(&reference, &submission, &GradeOptions::default());
+        let identifiers: Vec<&str> = report
+            .grades
+            .iter()
+            .map(|grade| grade.identifier.as_str())
+            .collect();
+        assert_eq!(
+            identifiers,
+            vec!["addition", "multiplication", "subtract1", "subtraction"]
+        );
+        assert!(report.grades[0].passed());
+        assert!(report.grades[1].passed());
+        assert_eq!(report.grades[2].expected_arguments_count, 1);
+        assert_eq!(report.grades[2].arguments_count, Some(2));
+        assert_eq!(report.grades[2].equivalence, None);
+        assert_eq!(
+            report.grades[3].equivalence,
+            Some(Equivalence::Counterexample(Counterexample {
+                arguments: vec![1, 1],
+                first: Evaluation::Defined(0),
+                second: Evaluation::Defined(1),
+            }))
+        );
+        assert_eq!(report.score(), 2);
+        assert_eq!(report.max_score(), 4);
+
+        let submission = parse_recur_functions("addition = [$p1.1, ($s:$p3.3)];")?;
+        let report = grade(&reference, &submission, &GradeOptions::default());
+        assert_eq!(report.grades[1].arguments_count, None);
+        assert_eq!(report.score(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn exhausted_test() -> anyhow::Result<()> {
+        let reference =
+            parse_recur_functions("addition = [$p1.1, ($s:$p3.3)]; subtract1 = [$z,$p2.1];")?;
+        let submission =
+            parse_recur_functions("addition = {($s:$p3.3), 4000000000}; subtract1 = [$z,$p2.1];")?;
+        let options = GradeOptions {
+            up_to: 3,
+            fuel: 10_000,
+            ..GradeOptions::default()
+        };
+        let report = grade(&reference, &submission, &options);
+        assert!(!report.grades[0].passed());
+        assert_eq!(
+            report.grades[0].equivalence,
+            Some(Equivalence::Counterexample(Counterexample {
+                arguments: vec![0, 0],
+                first: Evaluation::Defined(0),
+                second: Evaluation::Exhausted,
+            }))
+        );
+        assert!(report.to_json().contains(
+            r#""counterexample": { "arguments": [0, 0], "expected": 0, "actual": { "status": "exhausted" } },"#
+        ));
+        assert_eq!(report.score(), 1);
+
+        let report = grade(&submission, &submission, &options);
+        assert_eq!(
+            report.grades[0].equivalence,
+            Some(Equivalence::Equivalent {
+                checked: 16,
+                exhausted: 16
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn constraints_test() -> anyhow::Result<()> {
+        let reference = parse_recur_functions(REFERENCE)?;
+        let submission = parse_recur_functions(
+            "successor = $s;
+            addition = [$p1.1, (successor:$p3.3)];
+            multiplication = [$z , (addition: $p3.3,$p3.1)];
+            subtract1 = {($p3.1 : ($s : $p2.2), $p2.1, $p2.2), 10};",
+        )?;
+        let options = GradeOptions {
+            forbid_minimization: true,
+            allowed_identifiers: Some(vec!["addition".to_string()]),
+            ..GradeOptions::default()
+        };
+        let report = grade(&reference, &submission, &options);
+        assert_eq!(
+            report.grades[0].violations,
+            vec![Violation::ForbiddenIdentifier("successor".to_string())]
+        );
+        assert!(report.grades[1].violations.is_empty());
+        assert_eq!(report.grades[2].violations, vec![Violation::Minimization]);
+        assert_eq!(report.score(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn json_test() -> anyhow::Result<()> {
+        let reference =
+            parse_recur_functions("subtract1 = [$z,$p2.1]; double = [$z, ($s:($s:$p2.2))];")?;
+        let submission =
+            parse_recur_functions("subtract1 = [$z,$p2.1]; double = {($s:$p2.2), 10};")?;
+        let report = grade(&reference, &submission, &GradeOptions::default());
+        assert_eq!(
+            report.to_json(),
+            r#"{
+  "score": 1,
+  "max_score": 2,
+  "functions": [
+    {
+      "identifier": "double",
+      "passed": false,
+      "expected_arguments_count": 1,
+      "arguments_count": 1,
+      "checked": null,
+      "exhausted": null,
+      "counterexample": { "arguments": [0], "expected": 0, "actual": { "status": "undefined" } },
+      "violations": []
+    },
+    {
+      "identifier": "subtract1",
+      "passed": true,
+      "expected_arguments_count": 1,
+      "arguments_count": 1,
+      "checked": 11,
+      "exhausted": 0,
+      "counterexample": null,
+      "violations": []
+    }
+  ]
+}
+"#
+        );
+
+        let report = GradeReport {
+            grades: vec![FunctionGrade {
+                identifier: "double".to_string(),
+                expected_arguments_count: 1,
+                arguments_count: Some(1),
+                equivalence: Some(Equivalence::Counterexample(Counterexample {
+                    arguments: vec![3],
+                    first: Evaluation::Exhausted,
+                    second: Evaluation::Undefined,
+                })),
+                violations: Vec::new(),
+            }],
+        };
+        assert!(report.to_json().contains(
+            r#""counterexample": { "arguments": [3], "expected": { "status": "exhausted" }, "actual": { "status": "undefined" } },"#
+        ));
+
+        Ok(())
+    }
+}
@@ -90,6 +90,16 @@ mod grammar_tests {
         assert_eq!(pair.as_span().start(), 0);
         assert_eq!(pair.as_span().end(), 2);
 
+        let pair_res = RecurFunctionGrammar::parse(Rule::zero, "$z3");
+        assert!(pair_res.is_ok());
+        let pair = pair_res?
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("pair expected"))?;
+        assert_eq!(pair.as_str(), "$z3");
+        assert_eq!(pair.as_span().start(), 0);
+        assert_eq!(pair.as_span().end(), 3);
+        assert_eq!(pair.into_inner().next().unwrap().as_rule(), Rule::integer);
+
         let pair_res = RecurFunctionGrammar::parse(Rule::zero, "   $z   ");
         assert!(pair_res.is_err());
 
@@ -130,6 +140,15 @@ mod grammar_tests {
         assert_eq!(pair.as_span().start(), 0);
         assert_eq!(pair.as_span().end(), 5);
 
+        let pair_res = RecurFunctionGrammar::parse(Rule::projection, "$p.2");
+        assert!(pair_res.is_ok());
+        let pair = pair_res?
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("pair expected"))?;
+        assert_eq!(pair.as_str(), "$p.2");
+        assert_eq!(pair.as_span().start(), 0);
+        assert_eq!(pair.as_span().end(), 4);
+
         let pair_res = RecurFunctionGrammar::parse(Rule::projection, "$ p 1 . 1 ");
         assert!(pair_res.is_err());
 
@@ -151,6 +170,50 @@ mod grammar_tests {
         Ok(())
     }
 
+    #[test]
+    fn constant_test() -> anyhow::Result<()> {
+        let pair_res = RecurFunctionGrammar::parse(Rule::constant, "$c3.5");
+        assert!(pair_res.is_ok());
+        let pair = pair_res?
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("pair expected"))?;
+        assert_eq!(pair.as_str(), "$c3.5");
+        assert_eq!(pair.as_span().start(), 0);
+        assert_eq!(pair.as_span().end(), 5);
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::constant, "$c0.100");
+        assert!(pair_res.is_ok());
+        let pair = pair_res?
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("pair expected"))?;
+        assert_eq!(pair.as_str(), "$c0.100");
+        assert_eq!(pair.as_span().start(), 0);
+        assert_eq!(pair.as_span().end(), 7);
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::constant, "$c.5");
+        assert!(pair_res.is_ok());
+        let pair = pair_res?
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("pair expected"))?;
+        assert_eq!(pair.as_str(), "$c.5");
+        assert_eq!(pair.as_span().start(), 0);
+        assert_eq!(pair.as_span().end(), 4);
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::constant, "$ c 3 . 5 ");
+        assert!(pair_res.is_err());
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::constant, "$c");
+        assert!(pair_res.is_err());
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::constant, "$c3");
+        assert!(pair_res.is_err());
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::constant, "c3.5");
+        assert!(pair_res.is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn composition_test() -> anyhow::Result<()> {
         let pair_res = RecurFunctionGrammar::parse(Rule::composition, "($s:$p3.3)");
@@ -181,6 +244,18 @@ mod grammar_tests {
         assert_eq!(pair.as_span().end(), 14);
 
         let pair_res = RecurFunctionGrammar::parse(Rule::composition, "(s:)");
+        assert!(pair_res.is_ok());
+        let pair = pair_res?
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("pair expected"))?;
+        assert_eq!(pair.as_str(), "(s:)");
+        assert_eq!(pair.as_span().start(), 0);
+        assert_eq!(pair.as_span().end(), 4);
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::composition, "(:)");
+        assert!(pair_res.is_err());
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::composition, "(s:$p1.1,)");
         assert!(pair_res.is_err());
 
         let pair_res = RecurFunctionGrammar::parse(Rule::composition, "(:p3.3)");
@@ -229,9 +304,27 @@ mod grammar_tests {
         assert_eq!(pair.as_span().end(), 18);
 
         let pair_res = RecurFunctionGrammar::parse(Rule::primitive, "[1,($s:$p3.3)]");
+        assert!(pair_res.is_ok());
+        let pair = pair_res?
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("pair expected"))?;
+        assert_eq!(pair.as_str(), "[1,($s:$p3.3)]");
+        assert_eq!(pair.as_span().start(), 0);
+        assert_eq!(pair.as_span().end(), 14);
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::primitive, "[1.5,($s:$p3.3)]");
         assert!(pair_res.is_err());
 
         let pair_res = RecurFunctionGrammar::parse(Rule::primitive, "[$p1.1,1]");
+        assert!(pair_res.is_ok());
+        let pair = pair_res?
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("pair expected"))?;
+        assert_eq!(pair.as_str(), "[$p1.1,1]");
+        assert_eq!(pair.as_span().start(), 0);
+        assert_eq!(pair.as_span().end(), 9);
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::primitive, "[$p1.1,-1]");
         assert!(pair_res.is_err());
 
         let pair_res = RecurFunctionGrammar::parse(Rule::primitive, "[,($s:$p3.3)]");
@@ -276,6 +369,15 @@ mod grammar_tests {
         assert!(pair_res.is_err());
 
         let pair_res = RecurFunctionGrammar::parse(Rule::minimization, "{100,100}");
+        assert!(pair_res.is_ok());
+        let pair = pair_res?
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("pair expected"))?;
+        assert_eq!(pair.as_str(), "{100,100}");
+        assert_eq!(pair.as_span().start(), 0);
+        assert_eq!(pair.as_span().end(), 9);
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::minimization, "{100,$z}");
         assert!(pair_res.is_err());
 
         let pair_res = RecurFunctionGrammar::parse(Rule::minimization, "{subtractionAbs3 100}");
@@ -383,6 +485,16 @@ mod grammar_tests {
         );
 
         let pair_res = RecurFunctionGrammar::parse(Rule::recursive_function, "100");
+        assert!(pair_res.is_ok());
+        let pair = pair_res?
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("pair expected"))?;
+        assert_eq!(pair.as_str(), "100");
+        assert_eq!(pair.as_span().start(), 0);
+        assert_eq!(pair.as_span().end(), 3);
+        assert_eq!(pair.into_inner().next().unwrap().as_rule(), Rule::integer);
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::recursive_function, "-100");
         assert!(pair_res.is_err());
 
         let pair_res = RecurFunctionGrammar::parse(Rule::recursive_function, "");
@@ -391,6 +503,52 @@ mod grammar_tests {
         Ok(())
     }
 
+    #[test]
+    fn examples_test() -> anyhow::Result<()> {
+        let pair_res = RecurFunctionGrammar::parse(Rule::example, "addition 2 3 = 5");
+        assert!(pair_res.is_ok());
+        let pair = pair_res?
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("pair expected"))?;
+        assert_eq!(pair.into_inner().count(), 4);
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::examples, "f 0 = 1; f 3 = 4;");
+        assert!(pair_res.is_ok());
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::examples, "f 0 = 1 f 3 = 4");
+        assert!(pair_res.is_err());
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::examples, "f 0 =");
+        assert!(pair_res.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn allow_test() -> anyhow::Result<()> {
+        let pair_res = RecurFunctionGrammar::parse(Rule::allow, "@allow(unused, unused_counter)");
+        assert!(pair_res.is_ok());
+        let pair = pair_res?
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("pair expected"))?;
+        let lint_names: Vec<&str> = pair.into_inner().map(|pair| pair.as_str()).collect();
+        assert_eq!(lint_names, vec!["unused", "unused_counter"]);
+
+        let pair_res = RecurFunctionGrammar::parse(
+            Rule::functions,
+            "@allow(unused_argument) @allow(unused_previous) first = $p2.1;",
+        );
+        assert!(pair_res.is_ok());
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::allow, "@allow()");
+        assert!(pair_res.is_err());
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::functions, "@allow(unused)");
+        assert!(pair_res.is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn functions_test() -> anyhow::Result<()> {
         let pair_res = RecurFunctionGrammar::parse(Rule::functions, "const0=$z;");
@@ -422,6 +580,15 @@ mod grammar_tests {
         assert_eq!(pair.as_span().end(), 38);
 
         let pair_res = RecurFunctionGrammar::parse(Rule::functions, "const0=100;");
+        assert!(pair_res.is_ok());
+        let pair = pair_res?
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("pair expected"))?;
+        assert_eq!(pair.as_str(), "const0=100;");
+        assert_eq!(pair.as_span().start(), 0);
+        assert_eq!(pair.as_span().end(), 11);
+
+        let pair_res = RecurFunctionGrammar::parse(Rule::functions, "const0=1 00;");
         assert!(pair_res.is_err());
 
         let pair_res = RecurFunctionGrammar::parse(Rule::functions, "const0=$z");
@@ -0,0 +1,114 @@
+use recur_func_parser::graph::*;
+use recur_func_parser::*;
+use std::collections::BTreeSet;
+
+mod graph_tests {
+    use super::*;
+
+    const FUNCTIONS: &str = "addition = [$p1.1, ($s:$p3.3)];
+        multiplication = [$z , (addition: $p3.3,$p3.1)];
+        subtract1 = [$z,$p2.1];
+        subtraction = [$p1.1, (subtract1:$p3.3)];
+        root = {(addition: subtraction, multiplication), 10};";
+
+    #[test]
+    fn dependencies_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        assert_eq!(
+            dependencies(&identifier_functions["addition"]),
+            BTreeSet::new()
+        );
+        assert_eq!(
+            dependencies(&identifier_functions["root"]),
+            BTreeSet::from([
+                "addition".to_string(),
+                "multiplication".to_string(),
+                "subtraction".to_string()
+            ])
+        );
+
+        let graph = dependency_graph(&identifier_functions, Some("subtraction"))?;
+        assert_eq!(
+            graph.keys().collect::<Vec<_>>(),
+            vec!["subtract1", "subtraction"]
+        );
+        assert!(dependency_graph(&identifier_functions, Some("undefined")).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn dot_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        let options = GraphOptions {
+            identifier: Some("multiplication".to_string()),
+            color_minimization: false,
+        };
+        assert_eq!(
+            export_dependency_graph(&identifier_functions, GraphFormat::Dot, &options)?,
+            "digraph dependencies {
+    \"addition\";
+    \"multiplication\";
+    \"multiplication\" -> \"addition\";
+}
+"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn mermaid_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        let options = GraphOptions {
+            identifier: None,
+            color_minimization: true,
+        };
+        let graph = export_dependency_graph(&identifier_functions, GraphFormat::Mermaid, &options)?;
+        assert!(graph.starts_with("graph TD\n"));
+        assert!(graph.contains("    def_root[\"root\"]\n"));
+        assert!(graph.contains("    def_root --> def_subtraction\n"));
+        assert!(graph.contains("    class def_root minimization\n"));
+        assert!(!graph.contains("    class def_addition minimization\n"));
+
+        let identifier_functions =
+            parse_recur_functions("end = $p1.1; graph = (end : $s); class = (graph : end);")?;
+        let graph = export_dependency_graph(&identifier_functions, GraphFormat::Mermaid, &options)?;
+        assert!(graph.contains("    def_end[\"end\"]\n"));
+        assert!(graph.contains("    def_class --> def_end\n"));
+        assert!(graph.contains("    def_graph --> def_end\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn function_tree_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        assert_eq!(
+            export_function_tree("addition", &identifier_functions["addition"], 0),
+            "digraph \"addition\" {
+    n0 [label=\"primitive\\n2 arguments\"];
+    n1 [label=\"$p1.1\\n1 argument\"];
+    n0 -> n1 [label=\"base\"];
+    n2 [label=\"composition\\n3 arguments\"];
+    n3 [label=\"$s\\n1 argument\"];
+    n2 -> n3 [label=\"base\"];
+    n4 [label=\"$p3.3\\n3 arguments\"];
+    n2 -> n4 [label=\"1\"];
+    n0 -> n2 [label=\"step\"];
+}
+"
+        );
+
+        let tree = export_function_tree("root", &identifier_functions["root"], 0);
+        assert!(tree.contains("[label=\"minimization up to 10\\n1 argument\"]"));
+        assert!(tree.contains("[label=\"multiplication\\n2 arguments\", shape=box]"));
+        assert!(!tree.contains("style=dashed"));
+
+        let tree = export_function_tree("root", &identifier_functions["root"], 1);
+        assert!(tree.contains("style=dashed"));
+        assert!(tree.contains("[label=\"addition\\n2 arguments\", shape=box]"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use recur_func_parser::growth::*;
+use recur_func_parser::*;
+
+mod growth_tests {
+    use super::*;
+
+    const FUNCTIONS: &str = "addition = [$p1.1, ($s:$p3.3)];
+        multiplication = [$z , (addition: $p3.3,$p3.1)];
+        power = [1, (multiplication: $p3.3, $p3.1)];
+        tower = [1, (power: 2, $p2.2)];
+        double = (addition: $p1.1, $p1.1);
+        pow2 = [1, (double: $p2.2)];
+        subtract1 = [$z,$p2.1];
+        keep = [$z, $p3.3];";
+
+    #[test]
+    fn grzegorczyk_level_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        let level = |identifier: &str| estimate_growth(&identifier_functions[identifier]);
+        assert_eq!(level("subtract1").grzegorczyk_level, 0);
+        assert_eq!(level("addition").grzegorczyk_level, 1);
+        assert_eq!(level("double").grzegorczyk_level, 1);
+        assert_eq!(level("multiplication").grzegorczyk_level, 2);
+        assert_eq!(level("power").grzegorczyk_level, 3);
+        assert_eq!(level("pow2").grzegorczyk_level, 3);
+        assert_eq!(level("tower").grzegorczyk_level, 4);
+        assert!(!level("multiplication").is_enormous());
+        assert!(level("tower").is_enormous());
+
+        Ok(())
+    }
+
+    #[test]
+    fn primitive_depth_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        let depth = |identifier: &str| estimate_growth(&identifier_functions[identifier]);
+        assert_eq!(depth("addition").primitive_depth, 1);
+        assert_eq!(depth("multiplication").primitive_depth, 2);
+        assert_eq!(depth("power").primitive_depth, 3);
+        assert_eq!(depth("tower").primitive_depth, 4);
+        assert_eq!(depth("keep").primitive_depth, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn step_bound_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        let bound =
+            |identifier: &str| estimate_growth(&identifier_functions[identifier]).step_bound;
+        assert_eq!(bound("keep"), StepBound::Polynomial(0));
+        assert_eq!(bound("addition"), StepBound::Polynomial(1));
+        assert_eq!(bound("power"), StepBound::Arrow(1));
+        assert_eq!(bound("tower"), StepBound::Arrow(2));
+        assert_eq!(bound("addition").to_string(), "O(n)");
+        assert_eq!(StepBound::Polynomial(3).to_string(), "O(n^3)");
+        assert_eq!(bound("tower").to_string(), "2^^n");
+
+        Ok(())
+    }
+}
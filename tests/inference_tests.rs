@@ -0,0 +1,121 @@
+use recur_func_parser::*;
+
+mod inference_tests {
+    use super::*;
+
+    #[test]
+    fn composition_inference_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "addition = [$p1.1, ($s:$p3.3)];
+            swapped = (addition : $p.2, $p2.1);
+            plus5 = (addition : $p2.1, $c.5);",
+        )?;
+        assert_eq!(identifier_functions["swapped"].arguments_count(), 2);
+        assert_eq!(identifier_functions["plus5"].arguments_count(), 2);
+
+        let query = parse_query("swapped 3 4", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(7));
+
+        let query = parse_query("plus5 3 4", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(8));
+
+        Ok(())
+    }
+
+    #[test]
+    fn primitive_inference_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "addition = [$p1.1, ($s:$p.3)];
+            subtract1 = [$z, $p.1];
+            multiplication = [$z , (addition: $p.3, $p3.1)];
+            double = [$z, ($s:($s:$p.2))];",
+        )?;
+        assert_eq!(identifier_functions["addition"].arguments_count(), 2);
+        assert_eq!(identifier_functions["subtract1"].arguments_count(), 1);
+        assert_eq!(identifier_functions["multiplication"].arguments_count(), 2);
+        assert_eq!(identifier_functions["double"].arguments_count(), 1);
+
+        let query = parse_query("addition 4 5", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(9));
+
+        let query = parse_query("subtract1 4", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(3));
+
+        let query = parse_query("multiplication 4 5", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(20));
+
+        let query = parse_query("double 6", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(12));
+
+        Ok(())
+    }
+
+    #[test]
+    fn constant_inference_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "addition = [$p1.1, ($s:$p3.3)];
+            plus5 = (addition : $p2.1, $c.5);
+            add5 = [$c.5, ($s:$p3.3)];
+            below5 = {(addition : $p2.2, $c.5), 10};",
+        )?;
+        assert_eq!(identifier_functions["plus5"].arguments_count(), 2);
+        assert_eq!(identifier_functions["add5"].arguments_count(), 2);
+        assert_eq!(identifier_functions["below5"].arguments_count(), 1);
+
+        let query = parse_query("add5 1 3", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(8));
+
+        let query = parse_query("plus5 1 9", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(6));
+
+        Ok(())
+    }
+
+    #[test]
+    fn nested_inference_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "addition = [$p1.1, ($s:$p3.3)];
+            addition3 = (addition : (addition : $p.1, $p.2), $p3.3);
+            firstRoot = {($z : $p.2, $p2.1), 10};
+            rootWith = ({$p.2, 10} : $p1.1);",
+        )?;
+        assert_eq!(identifier_functions["addition3"].arguments_count(), 3);
+        assert_eq!(identifier_functions["firstRoot"].arguments_count(), 1);
+        assert_eq!(identifier_functions["rootWith"].arguments_count(), 1);
+
+        let query = parse_query("addition3 1 2 3", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(6));
+
+        let query = parse_query("rootWith 4", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn ambiguous_inference_test() -> anyhow::Result<()> {
+        assert!(matches!(
+            parse_recur_functions("first = $p.1;"),
+            Err(RecurFunctionParseError::AmbiguousArgumentsCount(_))
+        ));
+        assert!(matches!(
+            parse_recur_functions("next = ($s : $p.1);"),
+            Err(RecurFunctionParseError::AmbiguousArgumentsCount(_))
+        ));
+        assert!(matches!(
+            parse_recur_functions("root = {$p.1, 10};"),
+            Err(RecurFunctionParseError::AmbiguousArgumentsCount(_))
+        ));
+        assert!(matches!(
+            parse_recur_functions("wrong = ($s : $p.3, $p2.1);"),
+            Err(RecurFunctionParseError::InvalidArgumentsCount(_))
+        ));
+
+        assert!(matches!(
+            parse_recur_functions("five = $c.5;"),
+            Err(RecurFunctionParseError::AmbiguousArgumentsCount(_))
+        ));
+
+        Ok(())
+    }
+}
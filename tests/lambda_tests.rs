@@ -0,0 +1,134 @@
+use recur_func_parser::lambda::*;
+use recur_func_parser::*;
+
+mod common;
+
+mod lambda_tests {
+    use super::*;
+
+    const FUEL: u64 = 100_000;
+
+    #[test]
+    fn numeral_test() -> anyhow::Result<()> {
+        assert_eq!(church_numeral(2).to_string(), "λx0.λx1.x0 (x0 x1)");
+        for number in 0..5 {
+            assert_eq!(decode_numeral(&church_numeral(number)), Some(number));
+        }
+        assert_eq!(
+            decode_numeral(&Term::Abstraction(Box::new(Term::Variable(0)))),
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn normalize_test() -> anyhow::Result<()> {
+        let identity = Term::Abstraction(Box::new(Term::Variable(0)));
+        let (normal_form, steps) = normalize(
+            Term::Application(Box::new(identity.clone()), Box::new(church_numeral(3))),
+            FUEL,
+        )
+        .ok_or_else(|| anyhow::anyhow!("term has no normal form"))?;
+        assert_eq!(normal_form, church_numeral(3));
+        assert_eq!(steps, 1);
+        let self_application = Term::Abstraction(Box::new(Term::Application(
+            Box::new(Term::Variable(0)),
+            Box::new(Term::Variable(0)),
+        )));
+        let omega = Term::Application(
+            Box::new(self_application.clone()),
+            Box::new(self_application),
+        );
+        assert_eq!(normalize(omega.clone(), FUEL), None);
+        let constant = Term::Abstraction(Box::new(Term::Abstraction(Box::new(Term::Variable(1)))));
+        let discarded = Term::Application(
+            Box::new(Term::Application(
+                Box::new(constant),
+                Box::new(identity.clone()),
+            )),
+            Box::new(omega),
+        );
+        assert_eq!(normalize(discarded, FUEL), Some((identity, 2)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn translate_test() -> anyhow::Result<()> {
+        let identifier_functions = common::functions()?;
+        common::assert_computes(&identifier_functions, |_, function, arguments| {
+            run(&translate(function), arguments, FUEL).map(|(result, _)| result)
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn reduction_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "nullary = ($s:$z); big = 1000; addition = [$p1.1, ($s:$p3.3)];",
+        )?;
+        let (normal_form, _) = normalize(translate(&identifier_functions["nullary"]), FUEL)
+            .ok_or_else(|| anyhow::anyhow!("term has no normal form"))?;
+        assert_eq!(normal_form, church_numeral(1));
+        let (normal_form, _) = normalize(translate(&identifier_functions["big"]), FUEL)
+            .ok_or_else(|| anyhow::anyhow!("term has no normal form"))?;
+        assert_eq!(decode_numeral(&normal_form), Some(1000));
+        let addition = translate(&identifier_functions["addition"]);
+        let (result, steps) = run(&addition, &[2, 3], FUEL)
+            .ok_or_else(|| anyhow::anyhow!("term has no normal form"))?;
+        assert_eq!(result, 5);
+        assert_eq!(run(&addition, &[2, 3], steps), Some((5, steps)));
+        assert_eq!(run(&addition, &[2, 3], steps - 1), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn big_numeral_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions("id = $p1.1; next = ($s:$p1.1);")?;
+        let numeral = church_numeral(100_000);
+        assert_eq!(decode_numeral(&numeral.clone()), Some(100_000));
+        assert_eq!(numeral, church_numeral(100_000));
+        assert_eq!(
+            run(&translate(&identifier_functions["id"]), &[100_000], FUEL),
+            Some((100_000, 1))
+        );
+        assert_eq!(
+            run(&translate(&identifier_functions["next"]), &[100_000], FUEL)
+                .map(|(result, _)| result),
+            Some(100_001)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn strict_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "subtraction = [$p1.1, ([$z,$p2.1]:$p3.3)];
+            undefined = {($s:$p2.1), 3};
+            discarded = ($p2.1 : $p1.1, undefined);
+            undefinedBase = [undefined, $p3.2];
+            undefinedFirstStep = [$p1.1, {(subtraction: $c4.1, $p4.2), 3}];
+            boundedStep = [$p1.1, ($p3.3 : $p3.1, $p3.2, {(subtraction: $p4.3, $p4.2), 5})];",
+        )?;
+        for (identifier, function) in identifier_functions.iter() {
+            let term = translate(function);
+            let arguments_count = function.arguments_count() as usize;
+            for x in 0..3u32 {
+                for y in 0..3u32 {
+                    let arguments: Vec<u32> = [x, y].into_iter().take(arguments_count).collect();
+                    assert_eq!(
+                        run(&term, &arguments, 20_000).map(|(result, _)| result),
+                        execute(function, &arguments),
+                        "{identifier}{arguments:?}"
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+use recur_func_parser::lint::*;
+use recur_func_parser::*;
+
+mod lint_tests {
+    use super::*;
+
+    #[test]
+    fn unused_definition_test() -> anyhow::Result<()> {
+        let lints = lint(
+            "@allow(unused_counter) addition = [$p1.1, ($s:$p3.3)];
+            @allow(unused_counter)
+            multiplication = [$z , (addition: $p3.3,$p3.1)];
+            @allow(unused) double = (addition : $p1.1, $p1.1);",
+            &[],
+        )?;
+        assert_eq!(
+            lints,
+            vec![Lint {
+                kind: LintKind::UnusedDefinition,
+                identifier: "multiplication".to_string(),
+                line: 3,
+                column: 13,
+            }]
+        );
+        assert_eq!(
+            lints[0].to_string(),
+            "3:13: warning[unused]: function `multiplication` is never used"
+        );
+
+        let lints = lint(
+            "@allow(unused_counter) addition = [$p1.1, ($s:$p3.3)];
+            @allow(unused_counter) multiplication = [$z , (addition: $p3.3,$p3.1)];",
+            &["multiplication".to_string()],
+        )?;
+        assert!(lints.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unused_argument_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "second = ($s : ($p2.2 : $p3.1, $p3.3));
+            minimum = {($p3.1 : $p2.1, $p2.2, $p2.1), 10};
+            addition = [$p1.1, ($s:$p3.3)];",
+        )?;
+        assert_eq!(
+            used_arguments(&identifier_functions["second"]),
+            vec![false, false, true]
+        );
+        assert_eq!(used_arguments(&identifier_functions["minimum"]), vec![true]);
+        assert_eq!(
+            used_arguments(&identifier_functions["addition"]),
+            vec![true, true]
+        );
+
+        let lints = lint(
+            "second = ($s : ($p2.2 : $p3.1, $p3.3)); @allow(unused_argument) first = $p2.1;",
+            &["second".to_string(), "first".to_string()],
+        )?;
+        let kinds: Vec<LintKind> = lints.into_iter().map(|lint| lint.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![LintKind::UnusedArgument(1), LintKind::UnusedArgument(2)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn unused_step_arguments_test() -> anyhow::Result<()> {
+        let lints = lint(
+            "subtract1 = [$z,$p2.1];
+            addition = [$p1.1, ($s:$p3.3)];
+            @allow(unused_previous) predecessor = [$z,$p2.1];",
+            &[
+                "subtract1".to_string(),
+                "addition".to_string(),
+                "predecessor".to_string(),
+            ],
+        )?;
+        assert_eq!(
+            lints,
+            vec![
+                Lint {
+                    kind: LintKind::UnusedPreviousValue,
+                    identifier: "subtract1".to_string(),
+                    line: 1,
+                    column: 17,
+                },
+                Lint {
+                    kind: LintKind::UnusedCounter,
+                    identifier: "addition".to_string(),
+                    line: 2,
+                    column: 32,
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn nullary_step_test() -> anyhow::Result<()> {
+        let lints = lint(
+            "repeat = [($s:$z), $z];
+            seven = [$p1.1, 7];",
+            &["repeat".to_string(), "seven".to_string()],
+        )?;
+        let kinds: Vec<(String, LintKind)> = lints
+            .into_iter()
+            .map(|lint| (lint.identifier, lint.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("repeat".to_string(), LintKind::UnusedCounter),
+                ("repeat".to_string(), LintKind::UnusedPreviousValue),
+                ("seven".to_string(), LintKind::UnusedCounter),
+                ("seven".to_string(), LintKind::UnusedPreviousValue),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn lint_parse_error_test() -> anyhow::Result<()> {
+        assert!(lint("wrong = ($s : $z2, $p2.1);", &[]).is_err());
+        assert!(lint("@allow(unknown) f = $z;", &[]).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+use recur_func_parser::loop_program::*;
+use recur_func_parser::*;
+use std::collections::HashMap;
+
+mod common;
+
+mod loop_program_tests {
+    use super::*;
+
+    const FUEL: u64 = 1_000_000;
+
+    #[test]
+    fn display_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions("addition = [$p1.1, ($s:$p3.3)];")?;
+        let program = translate(&identifier_functions["addition"]);
+        assert_eq!(
+            program.to_string(),
+            "x3 := x1\nx4 := 0\nLOOP x2 DO\n  x5 := x3\n  x3 := x5\n  x3 := x3 + 1\n  x4 := x4 + 1\nEND\nx0 := x3\n"
+        );
+        assert!(program.is_loop_program());
+        assert_eq!(program.registers_count(), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn translate_test() -> anyhow::Result<()> {
+        let identifier_functions = common::functions()?;
+        for (identifier, function) in identifier_functions.iter() {
+            assert_eq!(
+                translate(function).is_loop_program(),
+                !["subtractionPart", "half"].contains(&identifier.as_str()),
+                "{identifier}"
+            );
+        }
+        common::assert_computes(&identifier_functions, |_, function, arguments| {
+            run(&translate(function), arguments, FUEL)
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_recur_function_test() -> anyhow::Result<()> {
+        let mut identifier_functions = common::functions()?;
+        let mut translated_functions = HashMap::new();
+        for (identifier, function) in identifier_functions.iter() {
+            let program = translate(function);
+            if !program.is_loop_program() {
+                assert!(to_recur_function(&program).is_err(), "{identifier}");
+                continue;
+            }
+            let translated = to_recur_function(&program)?;
+            assert_eq!(
+                translated.arguments_count(),
+                function.arguments_count(),
+                "{identifier}"
+            );
+            translated_functions.insert(identifier.clone(), translated);
+        }
+        identifier_functions.retain(|identifier, _| translated_functions.contains_key(identifier));
+        assert_eq!(identifier_functions.len(), 13);
+        common::assert_computes(&identifier_functions, |identifier, _, arguments| {
+            execute(&translated_functions[identifier], &arguments.to_vec())
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn loop_count_test() -> anyhow::Result<()> {
+        // x0 := x1 incrementing loop register in body
+        let copy = LoopProgram {
+            arguments_count: 1,
+            statements: vec![Statement::Loop(
+                1,
+                vec![Statement::Successor(1), Statement::Successor(0)],
+            )],
+        };
+        let function = to_recur_function(&copy)?;
+        for x in 0..5u32 {
+            assert_eq!(run(&copy, &[x], FUEL), Some(x));
+            assert_eq!(execute(&function, &vec![x]), Some(x));
+        }
+        let identifier_functions = parse_recur_functions("nullary = ($s:$z); successor = $s;")?;
+        let nullary = translate(&identifier_functions["nullary"]);
+        assert_eq!(nullary.arguments_count, 0);
+        let translated = to_recur_function(&nullary)?;
+        assert_eq!(translated.arguments_count(), 0);
+        assert_eq!(execute(&translated, &vec![]), Some(1));
+        let successor = translate(&identifier_functions["successor"]);
+        assert_eq!(run(&successor, &[u32::MAX], FUEL), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn while_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "subtract1 = [$z,$p2.1];
+            subtraction = [$p1.1, (subtract1:$p3.3)];
+            identity = {(subtraction:$p2.1,$p2.2), 10};
+            undefined = {($s:$p2.1), 3};",
+        )?;
+        let identity = translate(&identifier_functions["identity"]);
+        assert!(!identity.is_loop_program());
+        assert_eq!(run(&identity, &[7], FUEL), Some(7));
+        assert_eq!(run(&identity, &[11], FUEL), None);
+        let undefined = translate(&identifier_functions["undefined"]);
+        assert_eq!(run(&undefined, &[2], FUEL), None);
+        assert!(matches!(
+            to_recur_function(&undefined),
+            Err(LoopTranslationError::UntranslatableProgram(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn loop_program_test() -> anyhow::Result<()> {
+        // x0 := x1 - 1
+        let predecessor = LoopProgram {
+            arguments_count: 1,
+            statements: vec![
+                Statement::Zero(2),
+                Statement::Loop(1, vec![Statement::Assign(0, 2), Statement::Successor(2)]),
+            ],
+        };
+        let function = to_recur_function(&predecessor)?;
+        for x in 0..6u32 {
+            assert_eq!(run(&predecessor, &[x], FUEL), Some(x.saturating_sub(1)));
+            assert_eq!(execute(&function, &vec![x]), Some(x.saturating_sub(1)));
+        }
+        // x0 := fib(x1) swapping two registers
+        let fibonacci = LoopProgram {
+            arguments_count: 1,
+            statements: vec![
+                Statement::Zero(0),
+                Statement::Zero(2),
+                Statement::Successor(2),
+                Statement::Loop(
+                    1,
+                    vec![
+                        Statement::Assign(3, 2),
+                        Statement::Loop(0, vec![Statement::Successor(3)]),
+                        Statement::Assign(2, 0),
+                        Statement::Assign(0, 3),
+                    ],
+                ),
+            ],
+        };
+        assert_eq!(run(&fibonacci, &[10], FUEL), Some(55));
+        let function = to_recur_function(&fibonacci)?;
+        for (x, expected) in [0, 1, 1, 2, 3, 5].into_iter().enumerate() {
+            assert_eq!(run(&fibonacci, &[x as u32], FUEL), Some(expected));
+            assert_eq!(execute(&function, &vec![x as u32]), Some(expected));
+        }
+        // x0 := (x1 mod 3) + 1 rotating three registers
+        let rotation = LoopProgram {
+            arguments_count: 1,
+            statements: vec![
+                Statement::Successor(2),
+                Statement::Assign(3, 2),
+                Statement::Successor(3),
+                Statement::Assign(4, 3),
+                Statement::Successor(4),
+                Statement::Loop(
+                    1,
+                    vec![
+                        Statement::Assign(5, 2),
+                        Statement::Assign(2, 3),
+                        Statement::Assign(3, 4),
+                        Statement::Assign(4, 5),
+                    ],
+                ),
+                Statement::Assign(0, 2),
+            ],
+        };
+        let function = to_recur_function(&rotation)?;
+        for x in 0..4u32 {
+            assert_eq!(run(&rotation, &[x], FUEL), Some(x % 3 + 1));
+            assert_eq!(execute(&function, &vec![x]), Some(x % 3 + 1));
+        }
+        let endless = LoopProgram {
+            arguments_count: 0,
+            statements: vec![Statement::Successor(0), Statement::While(0, Vec::new())],
+        };
+        assert_eq!(run(&endless, &[], FUEL), None);
+        assert!(matches!(
+            to_recur_function(&endless),
+            Err(LoopTranslationError::UntranslatableProgram(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use recur_func_parser::notation::*;
+use recur_func_parser::*;
+
+mod notation_tests {
+    use super::*;
+
+    const FUNCTIONS: &str = "addition = [$p1.1, ($s:$p3.3)];
+        half = {(addition:$p2.2,$p2.2), 20};
+        mix = (addition: [$z, $p2.1], {$p2.2, 5});
+        const = 7;";
+
+    #[test]
+    fn latex_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        assert_eq!(
+            render(
+                "addition",
+                &identifier_functions["addition"],
+                Notation::Latex
+            ),
+            "% addition = [$p1.1, ($s : $p3.3)];
+\\begin{aligned}
+\\mathrm{addition}(x, 0) &= I^{1}_{1}(x) \\\\
+\\mathrm{addition}(x, y + 1) &= s(I^{3}_{3}(x, y, \\mathrm{addition}(x, y)))
+\\end{aligned}
+"
+        );
+        assert_eq!(
+            render("half", &identifier_functions["half"], Notation::Latex),
+            "% half = {(addition : $p2.2, $p2.2), 20};
+\\begin{aligned}
+\\mathrm{half}(x) &= \\mu z \\leq 20\\, [\\mathrm{addition}(I^{2}_{2}(x, z), I^{2}_{2}(x, z)) = 0]
+\\end{aligned}
+"
+        );
+        assert_eq!(
+            render("mix", &identifier_functions["mix"], Notation::Latex),
+            "% mix = (addition : [$z, $p2.1], {$p2.2, 5});
+\\begin{aligned}
+\\mathrm{mix}(x) &= \\mathrm{addition}(\\mathrm{mix}_{1}(x), \\mu z \\leq 5\\, [I^{2}_{2}(x, z) = 0]) \\\\
+\\mathrm{mix}_{1}(0) &= 0 \\\\
+\\mathrm{mix}_{1}(y + 1) &= I^{2}_{1}(y, \\mathrm{mix}_{1}(y))
+\\end{aligned}
+"
+        );
+        assert_eq!(
+            render("const", &identifier_functions["const"], Notation::Latex),
+            "% const = 7;\n\\begin{aligned}\n\\mathrm{const} &= 7\n\\end{aligned}\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn mathml_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions("next = ($s:$p2.1);")?;
+        assert_eq!(
+            render("next", &identifier_functions["next"], Notation::MathMl),
+            "<!-- next = ($s : $p2.1); -->
+<math display=\"block\">
+  <mtable>
+    <mtr><mtd columnalign=\"right\"><mrow><mi>next</mi><mo>(</mo><mover accent=\"true\"><mi>x</mi><mo>&#x00AF;</mo></mover><mo>)</mo></mrow></mtd>\
+<mtd columnalign=\"left\"><mo>=</mo><mrow><mi>s</mi><mo>(</mo><mrow><msubsup><mi>I</mi><mn>1</mn><mn>2</mn></msubsup>\
+<mo>(</mo><mover accent=\"true\"><mi>x</mi><mo>&#x00AF;</mo></mover><mo>)</mo></mrow><mo>)</mo></mrow></mtd></mtr>
+  </mtable>
+</math>
+"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn render_all_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(FUNCTIONS)?;
+        let rendered = render_all(&identifier_functions, Notation::MathMl);
+        let position = |identifier: &str| rendered.find(&format!("<!-- {identifier} = "));
+        assert!(position("addition") < position("half"));
+        assert!(position("addition") < position("mix"));
+        assert!(position("addition").is_some() && position("const").is_some());
+        assert_eq!(
+            rendered.matches("<math ").count(),
+            rendered.matches("</math>").count()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use recur_func_parser::*;
+
+mod nullary_tests {
+    use super::*;
+
+    #[test]
+    fn nullary_constant_test() -> anyhow::Result<()> {
+        let identifier_functions =
+            parse_recur_functions("const0 = $z; const2 = ($s:($s:$z)); same = (const2 :);")?;
+        assert_eq!(identifier_functions["const0"].arguments_count(), 0);
+        assert_eq!(identifier_functions["const2"].arguments_count(), 0);
+        assert_eq!(identifier_functions["same"].arguments_count(), 0);
+
+        let query = parse_query("const2", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(2));
+
+        let query = parse_query("same", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(2));
+
+        assert!(parse_query("const2 1", &identifier_functions).is_err());
+        assert!(parse_recur_functions("wrong = ($s :);").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn nullary_context_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "addition = [$p1.1, ($s:$p3.3)];
+            const0v2 = ($z : $p2.1);
+            addTwo = (addition : $p1.1, ($s:($s:$z)));
+            multiplication = [$z , (addition: $p3.3,$p3.1)];",
+        )?;
+        assert_eq!(identifier_functions["const0v2"].arguments_count(), 2);
+        assert_eq!(identifier_functions["addTwo"].arguments_count(), 1);
+        assert_eq!(identifier_functions["multiplication"].arguments_count(), 2);
+
+        let query = parse_query("addTwo 5", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(7));
+
+        let query = parse_query("multiplication 3 4", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(12));
+
+        assert!(parse_recur_functions("wrong = ($s : $p2.1, $p3.1);").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn nullary_primitive_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "notSignum = [($s:$z),($z:$p2.1)];
+            subtract1 = [$z,$p2.1];
+            two = ($s:($s:$z));
+            addTwo = [two, ($s:$p3.3)];",
+        )?;
+        assert_eq!(identifier_functions["notSignum"].arguments_count(), 1);
+        assert_eq!(identifier_functions["addTwo"].arguments_count(), 2);
+
+        let query = parse_query("notSignum 0", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(1));
+
+        let query = parse_query("notSignum 5", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(0));
+
+        let query = parse_query("subtract1 5", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(4));
+
+        let query = parse_query("addTwo 4 3", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(5));
+
+        let identifier_functions = parse_recur_functions("repeat = [($s:$z), $z];")?;
+        assert_eq!(identifier_functions["repeat"].arguments_count(), 1);
+        assert_eq!(
+            identifier_functions["repeat"].to_string(),
+            "[($s : $z), $z2]"
+        );
+
+        assert!(parse_recur_functions("wrong = [($z:$p1.1), $p2.1];").is_err());
+        assert!(parse_recur_functions(
+            "addition = [$p1.1, ($s:$p3.3)]; two = ($s:($s:$z)); wrong = (addition : $p1.1, two);"
+        )
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn nullary_minimization_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "subtract1 = [$z,$p2.1];
+            rootOfSubtract1 = {subtract1, 10};",
+        )?;
+        assert_eq!(identifier_functions["rootOfSubtract1"].arguments_count(), 0);
+
+        let query = parse_query("rootOfSubtract1", &identifier_functions)?;
+        assert_eq!(execute_query(&query, &identifier_functions), Some(0));
+
+        assert!(parse_recur_functions("wrong = {$z, 10};").is_err());
+
+        Ok(())
+    }
+}
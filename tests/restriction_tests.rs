@@ -0,0 +1,117 @@
+use recur_func_parser::*;
+use std::collections::HashMap;
+
+mod restriction_tests {
+    use super::*;
+
+    #[test]
+    fn allowed_identifiers_test() -> anyhow::Result<()> {
+        let restrictions = HashMap::from([(
+            "multiplication".to_string(),
+            Restrictions {
+                allowed_identifiers: Some(vec!["addition".to_string()]),
+                ..Restrictions::default()
+            },
+        )]);
+        let identifier_functions = parse_recur_functions_with_restrictions(
+            "addition = [$p1.1, ($s:$p3.3)]; multiplication = [$z , (addition: $p3.3,$p3.1)];",
+            &restrictions,
+        )?;
+        assert_eq!(identifier_functions["multiplication"].arguments_count(), 2);
+
+        let res = parse_recur_functions_with_restrictions(
+            "addition = [$p1.1, ($s:$p3.3)];
+            subtract1 = [$z,$p2.1];
+            multiplication = [$z , (addition: (subtract1: $p3.3),$p3.1)];",
+            &restrictions,
+        );
+        assert!(matches!(
+            res,
+            Err(RecurFunctionParseError::ForbiddenIdentifier(identifier)) if identifier == "subtract1"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn forbidden_functions_test() -> anyhow::Result<()> {
+        let restrictions = HashMap::from([(
+            "never".to_string(),
+            Restrictions {
+                forbid_minimization: true,
+                ..Restrictions::default()
+            },
+        )]);
+        assert!(parse_recur_functions_with_restrictions(
+            "addition = [$p1.1, ($s:$p3.3)]; never = ($s : addition);",
+            &restrictions
+        )
+        .is_ok());
+        let res = parse_recur_functions_with_restrictions(
+            "never = ($s : {($s:$p2.2), 10});",
+            &restrictions,
+        );
+        assert!(matches!(
+            res,
+            Err(RecurFunctionParseError::ForbiddenFunction(function)) if function == "{($s:$p2.2), 10}"
+        ));
+
+        let restrictions = HashMap::from([(
+            "addition".to_string(),
+            Restrictions {
+                forbid_primitive: true,
+                ..Restrictions::default()
+            },
+        )]);
+        assert!(matches!(
+            parse_recur_functions_with_restrictions(
+                "addition = [$p1.1, ($s:$p3.3)];",
+                &restrictions
+            ),
+            Err(RecurFunctionParseError::ForbiddenFunction(_))
+        ));
+        assert!(parse_recur_functions("addition = [$p1.1, ($s:$p3.3)];").is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn target_restrictions_test() -> anyhow::Result<()> {
+        let restrictions = HashMap::from([(
+            "multiplication".to_string(),
+            Restrictions {
+                allowed_identifiers: Some(vec!["addition".to_string(), "double".to_string()]),
+                forbid_primitive: true,
+                ..Restrictions::default()
+            },
+        )]);
+        let identifier_functions = parse_recur_functions_with_restrictions(
+            "addition = [$p1.1, ($s:$p3.3)];
+            double = [$z, ($s:($s:$p2.2))];
+            multiplication = (addition: double, $p1.1);",
+            &restrictions,
+        )?;
+        assert_eq!(identifier_functions["multiplication"].arguments_count(), 1);
+
+        let res = parse_recur_functions_with_restrictions(
+            "addition = [$p1.1, ($s:$p3.3)];
+            multiplication = [$z , (addition: $p3.3,$p3.1)];",
+            &restrictions,
+        );
+        assert!(matches!(
+            res,
+            Err(RecurFunctionParseError::ForbiddenFunction(function)) if function == "[$z , (addition: $p3.3,$p3.1)]"
+        ));
+
+        let res = parse_recur_functions_with_restrictions(
+            "addition = [$p1.1, ($s:$p3.3)];",
+            &restrictions,
+        );
+        assert!(matches!(
+            res,
+            Err(RecurFunctionParseError::UndefinedIdentifier(identifier)) if identifier == "multiplication"
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use recur_func_parser::enumerate::*;
+use recur_func_parser::synth::*;
+use recur_func_parser::*;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+mod synth_tests {
+    use super::*;
+
+    #[test]
+    fn parse_examples_test() -> anyhow::Result<()> {
+        let (identifier, examples) = parse_examples("f 0 = 1; f 3 = 4;")?;
+        assert_eq!(identifier, "f");
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    arguments: vec![0],
+                    result: 1
+                },
+                Example {
+                    arguments: vec![3],
+                    result: 4
+                }
+            ]
+        );
+        let (_, examples) = parse_examples("c = 5")?;
+        assert_eq!(examples[0].arguments, Vec::<u32>::new());
+
+        assert_eq!(
+            parse_examples("f 0 = 1; g 3 = 4").unwrap_err().to_string(),
+            "Examples have different identifiers: f and g"
+        );
+        assert!(parse_examples("f 0 = 1; f 3 4 = 4").is_err());
+        assert!(parse_examples("f 0 1").is_err());
+        assert!(parse_examples("").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn synthesize_test() -> anyhow::Result<()> {
+        let (_, examples) = parse_examples("f 0 = 2; f 3 = 2")?;
+        let options = SynthesisOptions {
+            max_size: 4,
+            ..SynthesisOptions::default()
+        };
+        assert_eq!(
+            synthesize(&examples, &HashMap::new(), &options),
+            Synthesis::NotFound
+        );
+        match synthesize(&examples, &HashMap::new(), &SynthesisOptions::default()) {
+            Synthesis::Found(function) => assert_eq!(function.to_string(), "($s : ($s : $z1))"),
+            synthesis => panic!("unexpected synthesis: {synthesis:?}"),
+        }
+        let options = SynthesisOptions {
+            enumeration: EnumerationOptions {
+                max_constant: Some(2),
+                ..EnumerationOptions::default()
+            },
+            ..SynthesisOptions::default()
+        };
+        match synthesize(&examples, &HashMap::new(), &options) {
+            Synthesis::Found(function) => assert_eq!(function.to_string(), "$c1.2"),
+            synthesis => panic!("unexpected synthesis: {synthesis:?}"),
+        }
+
+        let (_, examples) = parse_examples("f 0 = 0; f 3 = 2; f 5 = 4")?;
+        let options = SynthesisOptions {
+            enumeration: EnumerationOptions {
+                minimization_max: None,
+                ..EnumerationOptions::default()
+            },
+            ..SynthesisOptions::default()
+        };
+        match synthesize(&examples, &HashMap::new(), &options) {
+            Synthesis::Found(function) => assert_eq!(function.to_string(), "[$z, $p2.1]"),
+            synthesis => panic!("unexpected synthesis: {synthesis:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn definitions_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "addition = [$p1.1, ($s:$p3.3)]; multiplication = [$z , (addition: $p3.3,$p3.1)];",
+        )?;
+        let (_, examples) = parse_examples("g 2 3 = 7; g 0 0 = 1; g 1 1 = 2")?;
+        match synthesize(
+            &examples,
+            &identifier_functions,
+            &SynthesisOptions::default(),
+        ) {
+            Synthesis::Found(function) => {
+                assert_eq!(function.to_string(), "($s : multiplication)")
+            }
+            synthesis => panic!("unexpected synthesis: {synthesis:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn budget_test() -> anyhow::Result<()> {
+        let (_, examples) = parse_examples("f 1 2 3 = 1000")?;
+        let options = SynthesisOptions {
+            max_size: 50,
+            time_limit: Duration::ZERO,
+            ..SynthesisOptions::default()
+        };
+        assert!(matches!(
+            synthesize(&examples, &HashMap::new(), &options),
+            Synthesis::OutOfTime(_)
+        ));
+
+        let options = SynthesisOptions {
+            max_size: 50,
+            time_limit: Duration::from_millis(200),
+            ..SynthesisOptions::default()
+        };
+        let start = Instant::now();
+        assert!(matches!(
+            synthesize(&examples, &HashMap::new(), &options),
+            Synthesis::OutOfTime(_)
+        ));
+        assert!(start.elapsed() < Duration::from_secs(2));
+
+        let options = SynthesisOptions {
+            max_size: 50,
+            max_nodes: Some(1000),
+            ..SynthesisOptions::default()
+        };
+        assert!(matches!(
+            synthesize(&examples, &HashMap::new(), &options),
+            Synthesis::OutOfNodes(_)
+        ));
+
+        Ok(())
+    }
+}
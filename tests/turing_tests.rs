@@ -0,0 +1,98 @@
+use recur_func_parser::turing::*;
+use recur_func_parser::urm::{Instruction, UrmProgram};
+use recur_func_parser::*;
+
+mod common;
+
+mod turing_tests {
+    use super::*;
+
+    const FUEL: u64 = 500_000;
+
+    #[test]
+    fn encoding_test() -> anyhow::Result<()> {
+        assert_eq!(encode_arguments(&[2, 0, 1]), "#11##1#");
+        assert_eq!(encode_arguments(&[]), "#");
+        assert_eq!(decode_result("#1011#"), Some(3));
+        assert_eq!(decode_result("##"), Some(0));
+        assert_eq!(decode_result("#11"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_urm_test() -> anyhow::Result<()> {
+        let addition = UrmProgram {
+            instructions: vec![
+                Instruction::Zero(3),
+                Instruction::Jump(2, 3, 6),
+                Instruction::Successor(1),
+                Instruction::Successor(3),
+                Instruction::Jump(1, 1, 2),
+            ],
+            arguments_count: 2,
+        };
+        let machine = from_urm(&addition);
+        let simulation = machine
+            .simulate("#111#11#", FUEL)
+            .ok_or_else(|| anyhow::anyhow!("machine doesn't stop"))?;
+        assert!(simulation.accepted);
+        assert_eq!(simulation.tape, "#11111#");
+        let (result, steps) =
+            run(&machine, &[3, 4], FUEL).ok_or_else(|| anyhow::anyhow!("machine doesn't stop"))?;
+        assert_eq!(result, 7);
+        assert_eq!(run(&machine, &[3, 4], steps - 1), None);
+        let endless = from_urm(&UrmProgram {
+            instructions: vec![Instruction::Jump(1, 1, 1)],
+            arguments_count: 0,
+        });
+        assert_eq!(run(&endless, &[], FUEL), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn compile_test() -> anyhow::Result<()> {
+        let identifier_functions = common::functions()?;
+        common::assert_computes(&identifier_functions, |_, function, arguments| {
+            run(&compile(function), arguments, FUEL).map(|(result, _)| result)
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn tape_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "second = $p3.2; nullary = ($s:$z); zero = $z1; subtract1 = [$z,$p2.1];",
+        )?;
+        for (identifier, input, tape) in [
+            ("second", "##11##", "#11#"),
+            ("nullary", "#", "#1#"),
+            ("zero", "#111#", "#000#"),
+            ("subtract1", "#111#", "#110#"),
+        ] {
+            let simulation = compile(&identifier_functions[identifier])
+                .simulate(input, FUEL)
+                .ok_or_else(|| anyhow::anyhow!("{identifier} doesn't stop"))?;
+            assert!(simulation.accepted, "{identifier}");
+            assert_eq!(simulation.tape, tape, "{identifier}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn export_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions("successor = $s;")?;
+        let machine = compile(&identifier_functions["successor"]);
+        let table = machine.export("successor");
+        assert!(table.starts_with("name: successor\ninit: start\naccept: accept\n\nstart,#\n"));
+        assert_eq!(
+            table.lines().filter(|line| !line.is_empty()).count(),
+            3 + 2 * machine.transitions.len()
+        );
+
+        Ok(())
+    }
+}
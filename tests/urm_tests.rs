@@ -0,0 +1,107 @@
+use recur_func_parser::urm::*;
+use recur_func_parser::*;
+
+mod common;
+
+mod urm_tests {
+    use super::*;
+
+    const FUEL: u64 = 1_000_000;
+
+    #[test]
+    fn instruction_display_test() -> anyhow::Result<()> {
+        let program = UrmProgram {
+            instructions: vec![
+                Instruction::Zero(2),
+                Instruction::Successor(1),
+                Instruction::Transfer(1, 3),
+                Instruction::Jump(1, 2, 5),
+            ],
+            arguments_count: 1,
+        };
+        assert_eq!(
+            program.to_string(),
+            "1: Z(2)\n2: S(1)\n3: T(1, 3)\n4: J(1, 2, 5)\n"
+        );
+        assert_eq!(program.registers_count(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_test() -> anyhow::Result<()> {
+        let addition = UrmProgram {
+            instructions: vec![
+                Instruction::Zero(3),
+                Instruction::Jump(2, 3, 6),
+                Instruction::Successor(1),
+                Instruction::Successor(3),
+                Instruction::Jump(1, 1, 2),
+            ],
+            arguments_count: 2,
+        };
+        assert_eq!(run(&addition, &[3, 4], FUEL), Some(7));
+        assert_eq!(run(&addition, &[3, 4], 5), None);
+        let endless = UrmProgram {
+            instructions: vec![Instruction::Jump(1, 1, 1)],
+            arguments_count: 0,
+        };
+        assert_eq!(run(&endless, &[], FUEL), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn compile_test() -> anyhow::Result<()> {
+        let identifier_functions = common::functions()?;
+        common::assert_computes(&identifier_functions, |_, function, arguments| {
+            run(&compile(function), arguments, FUEL)
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn constant_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "eight = 8; nine = 9; big = 1000; bigSuccessor = ($s:$c1.1000);",
+        )?;
+        for (identifier, arguments, result) in [
+            ("eight", vec![], 8),
+            ("nine", vec![], 9),
+            ("big", vec![], 1000),
+            ("bigSuccessor", vec![7], 1001),
+        ] {
+            let program = compile(&identifier_functions[identifier]);
+            assert!(program.instructions.len() < 100, "{identifier}");
+            assert_eq!(
+                run(&program, &arguments, FUEL),
+                Some(result),
+                "{identifier}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn registers_test() -> anyhow::Result<()> {
+        let identifier_functions = parse_recur_functions(
+            "subtract1 = [$z,$p2.1];
+            subtraction = [$p1.1, (subtract1:$p3.3)];
+            swap = (subtraction: $p2.2, $p2.1);
+            fourth = $p4.4;
+            successor = $s;",
+        )?;
+        let swap = compile(&identifier_functions["swap"]);
+        assert_eq!(run(&swap, &[2, 5], FUEL), Some(3));
+        assert_eq!(run(&swap, &[5, 2], FUEL), Some(0));
+        let fourth = compile(&identifier_functions["fourth"]);
+        assert_eq!(run(&fourth, &[1, 2, 3, 4], FUEL), Some(4));
+        let successor = compile(&identifier_functions["successor"]);
+        assert_eq!(run(&successor, &[u32::MAX - 1], FUEL), Some(u32::MAX));
+        assert_eq!(run(&successor, &[u32::MAX], FUEL), None);
+
+        Ok(())
+    }
+}